@@ -0,0 +1,19 @@
+use crate::Result;
+
+/// Returns how many bytes have to be skipped from `offset` to reach the next
+/// multiple of `boundary`.
+///
+/// `boundary` must be a power of two, which also means that the low bits of
+/// `offset` are all that matter, even if it doesn't fit in a `usize`.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+pub(crate) fn padding(offset: u64, boundary: usize) -> Result<usize> {
+    if !boundary.is_power_of_two() {
+        return Err(error!(
+            InvalidInput,
+            "alignment boundary is not a power of two"
+        ));
+    }
+
+    Ok((offset as usize).wrapping_neg() & (boundary - 1))
+}
@@ -0,0 +1,91 @@
+use crate::{Result, SliceReader};
+
+use alloc::vec::Vec;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// A buffer holding the entire contents of an [`AsyncRead`] source, so it can
+/// be parsed with [`Read<'data>`].
+///
+/// Parsers written against [`Read<'data>`] are synchronous, so they can't
+/// await more bytes from an asynchronous source. An `AsyncBufferedReader`
+/// bridges the two by reading the whole source into memory first. Afterwards,
+/// [`reader`] returns a [`SliceReader<'data>`] over the buffered bytes, which
+/// borrows from this buffer, so every read is zero-copy.
+///
+/// As the whole source is buffered, this is only suitable for sources of a
+/// bounded size. To protect against unbounded sources, limit them with
+/// [`AsyncReadExt::take`] beforehand.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use zc_io::{AsyncBufferedReader, Read};
+///
+/// # tokio::runtime::Builder::new_current_thread().build()?.block_on(async {
+/// let source: &[u8] = b"\x05hello";
+/// let buffered = AsyncBufferedReader::read_from(source).await?;
+///
+/// let mut reader = buffered.reader();
+/// let len = reader.read_next()?;
+/// assert!(matches!(reader.read_slice(len.into())?, Cow::Borrowed(b"hello")));
+/// # Ok::<(), zc_io::Error>(())
+/// # })?;
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`AsyncRead`]: tokio::io::AsyncRead
+/// [`Read<'data>`]: crate::Read
+/// [`reader`]: AsyncBufferedReader::reader
+/// [`SliceReader<'data>`]: SliceReader
+/// [`AsyncReadExt::take`]: tokio::io::AsyncReadExt::take
+#[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AsyncBufferedReader {
+    buf: Vec<u8>,
+}
+
+impl AsyncBufferedReader {
+    /// Reads `source` until end-of-file, and buffers everything it returned.
+    ///
+    /// # Errors
+    ///
+    /// If `source` returns an error, other than [`ErrorKind::Interrupted`],
+    /// which is retried, it is returned, and everything read so far is
+    /// discarded.
+    ///
+    /// [`ErrorKind::Interrupted`]: crate::ErrorKind::Interrupted
+    #[inline]
+    pub async fn read_from<R>(mut source: R) -> Result<Self>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut buf = Vec::new();
+        source.read_to_end(&mut buf).await?;
+        Ok(AsyncBufferedReader { buf })
+    }
+
+    /// Returns a reader over the buffered bytes, starting at the beginning.
+    ///
+    /// Every call returns a new reader, so the buffered bytes can be parsed
+    /// more than once.
+    #[must_use]
+    #[inline]
+    pub fn reader(&self) -> SliceReader<'_> {
+        SliceReader::new(&self.buf)
+    }
+
+    /// Returns the buffered bytes.
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Unwraps the `AsyncBufferedReader`, returning the buffered bytes.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{BufRead, ErrorKind, Peek, Read, Result};
+
+use alloc::{borrow::Cow, vec, vec::Vec};
+use core::{cmp, fmt};
+use std::io;
+
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// The `BufReader<R>` struct adds buffering to any [`io::Read`] source.
+///
+/// Reading from an unbuffered source through an [`IoReader<R>`] results in a
+/// system call for every [`read_next`]. A `BufReader<R>` instead reads from
+/// its source in large chunks, and serves small reads from an internal buffer.
+///
+/// Like [`IoReader<R>`], the [`Read<'data>`] implementation of a
+/// `BufReader<R>` always returns [`Owned`] values, since the internal buffer
+/// does not live for `'data`. When a requested slice fits in the buffer,
+/// [`read_slice_buffered`] can borrow it from the buffer instead, for as long
+/// as the `BufReader<R>` isn't used again.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use zc_io::{BufReader, Read};
+///
+/// let source: &[u8] = b"\x05hello\x05world";
+/// let mut reader = BufReader::new(source);
+///
+/// let len = reader.read_next()?;
+/// assert_eq!(reader.read_slice(len.into())?.as_ref(), b"hello");
+///
+/// let len = reader.read_next()?;
+/// assert!(matches!(reader.read_slice_buffered(len.into())?, Cow::Borrowed(b"world")));
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`IoReader<R>`]: crate::IoReader
+/// [`read_next`]: Read::read_next
+/// [`Read<'data>`]: Read
+/// [`Owned`]: Cow::Owned
+/// [`read_slice_buffered`]: BufReader::read_slice_buffered
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub struct BufReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    filled: usize,
+}
+
+impl<R> BufReader<R>
+where
+    R: io::Read,
+{
+    /// Creates a new `BufReader<R>` with a default buffer capacity, which is
+    /// currently 8 KiB.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: R) -> Self {
+        BufReader::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufReader<R>` with a buffer of at least `capacity`
+    /// bytes.
+    ///
+    /// Every read goes through the buffer, so it always holds at least one
+    /// byte, even if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BufRead, BufReader, Read};
+    ///
+    /// let mut reader = BufReader::with_capacity(0, &b"abc"[..]);
+    /// assert_eq!(reader.capacity(), 1);
+    /// assert_eq!(reader.read_next()?, b'a');
+    /// assert_eq!(reader.fill_buf()?, b"b");
+    /// assert_eq!(reader.read_array()?, *b"bc");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        BufReader {
+            inner,
+            buf: vec![0; cmp::max(capacity, 1)],
+            pos: 0,
+            filled: 0,
+        }
+    }
+
+    /// Reads `len` bytes from this reader, borrowing them from the internal
+    /// buffer whenever possible.
+    ///
+    /// If `len` is no larger than [`capacity`], the bytes are moved to the
+    /// front of the buffer if necessary, the buffer is refilled until it holds
+    /// them, and a [`Borrowed`] slice of the buffer is returned. Otherwise,
+    /// this falls back to [`read_slice`] and returns an [`Owned`] value.
+    ///
+    /// The returned slice borrows this reader, so the next read, which may
+    /// refill the buffer, can only happen once the slice is no longer in use:
+    ///
+    /// ```compile_fail
+    /// use zc_io::{BufReader, Read};
+    ///
+    /// let mut reader = BufReader::new(&b"abcd"[..]);
+    /// let first = reader.read_slice_buffered(2)?;
+    /// let second = reader.read_slice_buffered(2)?;
+    /// assert_ne!(first, second);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if fewer than `len`
+    /// bytes remain in this reader. In this case, no bytes are consumed.
+    ///
+    /// If any other error is encountered then this function immediately
+    /// returns. Any bytes that were read before the error remain buffered.
+    ///
+    /// [`capacity`]: BufReader::capacity
+    /// [`Borrowed`]: Cow::Borrowed
+    /// [`read_slice`]: Read::read_slice
+    /// [`Owned`]: Cow::Owned
+    #[inline]
+    pub fn read_slice_buffered(&mut self, len: usize) -> Result<Cow<'_, [u8]>> {
+        if len > self.buf.len() {
+            return self.read_slice(len);
+        }
+
+        if !self.fill_to(len)? {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        let start = self.pos;
+        self.pos += len;
+        Ok(Cow::Borrowed(&self.buf[start..self.pos]))
+    }
+
+    /// Reads from the underlying reader into the free space at the end of the
+    /// buffer, returning the number of bytes read.
+    fn fill_more(&mut self) -> Result<usize> {
+        loop {
+            match self.inner.read(&mut self.buf[self.filled..]) {
+                Ok(n) => {
+                    self.filled += n;
+                    return Ok(n);
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    /// Refills the buffer until it holds at least `len` bytes, which must not
+    /// exceed its capacity, returning `false` if the underlying reader reached
+    /// end-of-file first.
+    fn fill_to(&mut self, len: usize) -> Result<bool> {
+        if self.buf.len() - self.pos < len {
+            self.buf.copy_within(self.pos..self.filled, 0);
+            self.filled -= self.pos;
+            self.pos = 0;
+        }
+
+        while self.filled - self.pos < len {
+            if self.fill_more()? == 0 {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R> BufReader<R> {
+    /// Returns the bytes that are currently buffered.
+    ///
+    /// Unlike [`fill_buf`], this never reads from the underlying reader.
+    ///
+    /// [`fill_buf`]: io::BufRead::fill_buf
+    #[must_use]
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+
+    /// Returns the number of bytes the internal buffer can hold at once.
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid reading directly from the underlying
+    /// reader, as any buffered bytes would then be out of order.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `BufReader<R>`, returning the underlying reader.
+    ///
+    /// Note that any leftover data in the internal buffer is lost.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> fmt::Debug for BufReader<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufReader")
+            .field("reader", &self.inner)
+            .field(
+                "buffer",
+                &format_args!("{}/{}", self.filled - self.pos, self.buf.len()),
+            )
+            .finish()
+    }
+}
+
+impl<'data, R> Read<'data> for BufReader<R>
+where
+    R: io::Read,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        if self.pos == self.filled && !self.fill_to(1)? {
+            return Err(error!(UnexpectedEof, "failed to read byte"));
+        }
+
+        let byte = self.buf[self.pos];
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = vec![0; len];
+        self.read_exact_into(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    /// Fills `buf` from the internal buffer, refilling it as needed.
+    ///
+    /// Requests larger than the [`capacity`] of the buffer bypass it: the
+    /// buffered bytes are copied first, and the rest is read from the
+    /// underlying reader directly.
+    ///
+    /// [`capacity`]: BufReader::capacity
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.len() <= self.buf.len() {
+            if !self.fill_to(buf.len())? {
+                return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+            }
+
+            buf.copy_from_slice(&self.buf[self.pos..self.pos + buf.len()]);
+            self.pos += buf.len();
+            return Ok(());
+        }
+
+        let (head, tail) = buf.split_at_mut(self.filled - self.pos);
+        head.copy_from_slice(self.buffer());
+        self.pos = 0;
+        self.filled = 0;
+        self.inner.read_exact(tail)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, mut n: usize) -> Result<()> {
+        loop {
+            let buffered = self.filled - self.pos;
+            if n <= buffered {
+                self.pos += n;
+                return Ok(());
+            }
+
+            n -= buffered;
+            self.pos = 0;
+            self.filled = 0;
+            if self.fill_more()? == 0 {
+                return Err(error!(UnexpectedEof, "failed to skip bytes"));
+            }
+        }
+    }
+}
+
+/// Peeking at more bytes than the [`capacity`] of the internal buffer grows
+/// the buffer to hold them.
+///
+/// [`capacity`]: BufReader::capacity
+impl<R> Peek<'_> for BufReader<R>
+where
+    R: io::Read,
+{
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if N > self.buf.len() {
+            self.buf.resize(N, 0);
+        }
+
+        if !self.fill_to(N)? {
+            return Err(error!(UnexpectedEof, "failed to peek array"));
+        }
+
+        let mut array = [0; N];
+        array.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        Ok(array)
+    }
+}
+
+impl<R> BufRead<'_> for BufReader<R>
+where
+    R: io::Read,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        if self.pos == self.filled {
+            self.pos = 0;
+            self.filled = 0;
+            self.fill_more()?;
+        }
+
+        Ok(self.buffer())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos.saturating_add(amt), self.filled);
+    }
+}
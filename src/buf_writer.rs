@@ -0,0 +1,339 @@
+use crate::{Error, Result, Write};
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    mem::{self, ManuallyDrop},
+    ptr, result,
+};
+
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// The `BufWriter<W>` struct adds buffering to any [`Write`].
+///
+/// Many small writes to an unbuffered writer can be inefficient. A
+/// `BufWriter<W>` keeps an in-memory buffer of data and writes it to the
+/// underlying writer in large, infrequent batches: whenever a write would not
+/// fit in the buffer, and whenever [`flush`] is called. Unlike
+/// `std::io::BufWriter<W>`, it works with any [`Write`], including in
+/// `no_std` environments.
+///
+/// When a `BufWriter<W>` is dropped, its buffered data is written out, but
+/// any error that happens in the process is silently ignored. It is critical
+/// to call [`flush`] before the `BufWriter<W>` is dropped in order to observe
+/// such errors.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{BufWriter, Write};
+///
+/// let mut writer = BufWriter::with_capacity(4, Vec::new());
+///
+/// writer.write_all(b"ab")?;
+/// assert!(writer.get_ref().is_empty());
+/// assert_eq!(writer.buffer(), b"ab");
+///
+/// writer.write_all(b"cde")?;
+/// assert_eq!(writer.get_ref(), b"ab");
+///
+/// writer.flush()?;
+/// assert_eq!(writer.get_ref(), b"abcde");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`flush`]: Write::flush
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub struct BufWriter<W>
+where
+    W: Write,
+{
+    inner: W,
+    buf: Vec<u8>,
+    // Set while the underlying writer is being called, so that a panic in it
+    // won't cause `Drop` to write the same data again.
+    panicked: bool,
+}
+
+impl<W> BufWriter<W>
+where
+    W: Write,
+{
+    /// Creates a new `BufWriter<W>` with a default buffer capacity, which is
+    /// currently 8 KiB.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        BufWriter::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufWriter<W>` with a buffer of at least `capacity`
+    /// bytes.
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        BufWriter {
+            inner,
+            buf: Vec::with_capacity(capacity),
+            panicked: false,
+        }
+    }
+
+    /// Returns the bytes that are currently buffered.
+    #[must_use]
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the number of bytes the internal buffer can hold without
+    /// writing to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Care should be taken to avoid writing directly to the underlying
+    /// writer, as any buffered bytes would then be written out of order.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the `BufWriter<W>`, returning the underlying writer.
+    ///
+    /// The buffer is written out before returning the writer.
+    ///
+    /// # Errors
+    ///
+    /// An [`IntoInnerError`] is returned if the buffered data could not be
+    /// written. It holds both the error and the `BufWriter<W>`, which still
+    /// holds the bytes that weren't written, so nothing is lost. It converts
+    /// into an [`Error`], so it can be propagated with `?` when the writer is
+    /// no longer needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BufWriter, ErrorKind, LimitWriter, Write};
+    ///
+    /// let mut writer = BufWriter::new(LimitWriter::new(Vec::new(), 2));
+    /// writer.write_all(b"abcd")?;
+    ///
+    /// let error = writer.into_inner().unwrap_err();
+    /// assert_eq!(error.error().kind(), ErrorKind::WriteZero);
+    ///
+    /// let writer = error.into_inner();
+    /// assert_eq!(writer.get_ref().get_ref(), b"ab");
+    /// assert_eq!(writer.buffer(), b"cd");
+    ///
+    /// fn finish(writer: BufWriter<Vec<u8>>) -> zc_io::Result<Vec<u8>> {
+    ///     Ok(writer.into_inner()?)
+    /// }
+    ///
+    /// let mut writer = BufWriter::new(Vec::new());
+    /// writer.write_all(b"abcd")?;
+    /// assert_eq!(finish(writer)?, b"abcd");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    #[inline]
+    pub fn into_inner(mut self) -> result::Result<W, IntoInnerError<Self>> {
+        match self.flush_buf() {
+            Ok(()) => Ok(self.into_parts()),
+            Err(error) => Err(IntoInnerError(self, error)),
+        }
+    }
+
+    /// Takes the underlying writer out of this `BufWriter<W>` without running
+    /// its `Drop` implementation, discarding any buffered bytes.
+    fn into_parts(self) -> W {
+        let mut this = ManuallyDrop::new(self);
+        drop(mem::take(&mut this.buf));
+        // SAFETY: `this` is never dropped, and `inner` is not used again after
+        // being moved out of it.
+        unsafe { ptr::read(ptr::addr_of!(this.inner)) }
+    }
+
+    /// Writes the entire buffer to the underlying writer.
+    ///
+    /// If an error occurs, the bytes that were written are removed from the
+    /// buffer, and the rest are kept to be written later.
+    fn flush_buf(&mut self) -> Result<()> {
+        let mut written = 0;
+        let result = loop {
+            if written == self.buf.len() {
+                break Ok(());
+            }
+
+            self.panicked = true;
+            let result = self.inner.write(&self.buf[written..]);
+            self.panicked = false;
+
+            match result {
+                Ok(0) => {
+                    break Err(error!(WriteZero, "failed to write the buffered data"));
+                }
+                Ok(n) => written += n,
+                Err(ref error) if error.is_interrupted() => {}
+                Err(error) => break Err(error),
+            }
+        };
+
+        self.buf.drain(..written);
+        result
+    }
+}
+
+impl<W> fmt::Debug for BufWriter<W>
+where
+    W: Write + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufWriter")
+            .field("writer", &self.inner)
+            .field(
+                "buffer",
+                &format_args!("{}/{}", self.buf.len(), self.buf.capacity()),
+            )
+            .field("panicked", &self.panicked)
+            .finish()
+    }
+}
+
+impl<W> Write for BufWriter<W>
+where
+    W: Write,
+{
+    /// Writes `buf` into the internal buffer, first writing out the buffer if
+    /// `buf` would not fit.
+    ///
+    /// A `buf` that is at least as large as the [`capacity`] is written to the
+    /// underlying writer directly, bypassing the buffer.
+    ///
+    /// [`capacity`]: BufWriter::capacity
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if self.buf.len() + buf.len() > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+
+        if buf.len() >= self.buf.capacity() {
+            self.panicked = true;
+            let result = self.inner.write(buf);
+            self.panicked = false;
+            result
+        } else {
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        if self.buf.len() + buf.len() > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+
+        if buf.len() >= self.buf.capacity() {
+            self.panicked = true;
+            let result = self.inner.write_all(buf);
+            self.panicked = false;
+            result
+        } else {
+            self.buf.extend_from_slice(buf);
+            Ok(())
+        }
+    }
+}
+
+impl<W> Drop for BufWriter<W>
+where
+    W: Write,
+{
+    fn drop(&mut self) {
+        if !self.panicked {
+            // Errors can't be reported from `drop`, so they are ignored.
+            let _ = self.flush_buf();
+        }
+    }
+}
+
+/// An error returned by [`BufWriter::into_inner`], which combines an error
+/// that happened while writing out the buffer, and the buffered writer object
+/// which may be used to recover from the condition.
+///
+/// Converting it into an [`Error`], such as with `?`, discards the writer.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct IntoInnerError<W>(W, Error);
+
+impl<W> IntoInnerError<W> {
+    /// Returns the error which caused the call to [`BufWriter::into_inner`]
+    /// to fail.
+    #[must_use]
+    #[inline]
+    pub fn error(&self) -> &Error {
+        &self.1
+    }
+
+    /// Returns the buffered writer instance which generated the error.
+    ///
+    /// The returned object can be used for error recovery, such as
+    /// re-inspecting the buffer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Consumes the `IntoInnerError<W>` and returns the error which caused
+    /// the call to [`BufWriter::into_inner`] to fail, discarding the writer.
+    #[must_use]
+    #[inline]
+    pub fn into_error(self) -> Error {
+        self.1
+    }
+
+    /// Consumes the `IntoInnerError<W>` and returns the error which caused
+    /// the call to [`BufWriter::into_inner`] to fail, and the buffered writer
+    /// instance which generated it.
+    #[must_use]
+    #[inline]
+    pub fn into_parts(self) -> (Error, W) {
+        (self.1, self.0)
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for Error {
+    #[inline]
+    fn from(error: IntoInnerError<W>) -> Self {
+        error.1
+    }
+}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.1, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> std::error::Error for IntoInnerError<W> where W: fmt::Debug {}
@@ -0,0 +1,369 @@
+use crate::{Read, ReadExt, Result, Write, WriteExt};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Describes the order in which the bytes of a [`Primitive`] are laid out.
+///
+/// This trait is sealed and cannot be implemented outside of [`zc_io`]. It is
+/// implemented by the zero-sized types [`LittleEndian`], [`BigEndian`], and
+/// [`NativeEndian`], which are meant to be used as type parameters, such as
+/// with [`ReadExt::read_int`] and [`WriteExt::write_int`].
+///
+/// [`zc_io`]: crate
+/// [`ReadExt::read_int`]: crate::ReadExt::read_int
+/// [`WriteExt::write_int`]: crate::WriteExt::write_int
+pub trait ByteOrder: private::Sealed {
+    #[doc(hidden)]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: Primitive;
+
+    #[doc(hidden)]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: Primitive;
+}
+
+/// Little-endian byte order, where the least significant byte comes first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LittleEndian {}
+
+impl private::Sealed for LittleEndian {}
+
+impl ByteOrder for LittleEndian {
+    #[inline]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: Primitive,
+    {
+        T::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: Primitive,
+    {
+        value.to_le_bytes()
+    }
+}
+
+/// Big-endian byte order, where the most significant byte comes first.
+///
+/// This is also known as network byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BigEndian {}
+
+impl private::Sealed for BigEndian {}
+
+impl ByteOrder for BigEndian {
+    #[inline]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: Primitive,
+    {
+        T::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: Primitive,
+    {
+        value.to_be_bytes()
+    }
+}
+
+/// The byte order of the target platform.
+///
+/// This behaves like either [`LittleEndian`] or [`BigEndian`], depending on
+/// the platform being compiled for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NativeEndian {}
+
+impl private::Sealed for NativeEndian {}
+
+impl ByteOrder for NativeEndian {
+    #[inline]
+    fn from_bytes<T>(bytes: T::Bytes) -> T
+    where
+        T: Primitive,
+    {
+        T::from_ne_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_bytes<T>(value: T) -> T::Bytes
+    where
+        T: Primitive,
+    {
+        value.to_ne_bytes()
+    }
+}
+
+/// A primitive numeric type that can be read or written with a [`ByteOrder`].
+///
+/// This trait is sealed and cannot be implemented outside of [`zc_io`]. It is
+/// implemented for every fixed-width integer type, from [`u8`] to [`u128`]
+/// and from [`i8`] to [`i128`], as well as for the [`Float`] types [`f32`] and
+/// [`f64`].
+///
+/// Every implementation knows its size as the associated constant
+/// [`SIZE`], and converts from and to a fixed-size byte array of exactly that
+/// length. This keeps generic reads and writes, such as
+/// [`ReadExt::read_int`], on stable Rust, without requiring
+/// `size_of::<T>()` to be usable in a const-generic position. As [`SIZE`] is a
+/// regular constant, it can size buffers in generic code:
+///
+/// ```
+/// use zc_io::{BigEndian, Primitive, ReadExt};
+///
+/// fn read_all<T: Primitive>(bytes: &[u8]) -> zc_io::Result<Vec<T>> {
+///     let mut reader = bytes;
+///     let mut values = Vec::with_capacity(bytes.len() / T::SIZE);
+///     while !reader.is_empty() {
+///         values.push(reader.read_int::<T, BigEndian>()?);
+///     }
+///     Ok(values)
+/// }
+///
+/// const LEN: usize = <u32 as Primitive>::SIZE;
+/// let mut bytes = [0; 2 * LEN];
+/// bytes[2..LEN].copy_from_slice(&[0x01, 0x02]);
+/// bytes[LEN + 2..].copy_from_slice(&[0x01, 0x02]);
+///
+/// assert_eq!(read_all::<u32>(&bytes)?, [0x0102, 0x0102]);
+/// assert_eq!(read_all::<u16>(&bytes)?, [0x0000, 0x0102, 0x0000, 0x0102]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`zc_io`]: crate
+/// [`SIZE`]: Primitive::SIZE
+/// [`ReadExt::read_int`]: crate::ReadExt::read_int
+pub trait Primitive: private::Sealed + Sized {
+    /// The size of this type in bytes.
+    const SIZE: usize;
+
+    #[doc(hidden)]
+    type Bytes: AsRef<[u8]>;
+
+    #[doc(hidden)]
+    fn read_bytes<'data, R>(reader: &mut R) -> Result<Self::Bytes>
+    where
+        R: ?Sized + Read<'data>;
+
+    #[doc(hidden)]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    #[doc(hidden)]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    #[doc(hidden)]
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+
+    #[doc(hidden)]
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    #[doc(hidden)]
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    #[doc(hidden)]
+    fn to_ne_bytes(self) -> Self::Bytes;
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ident)*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl Primitive for $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+
+            type Bytes = [u8; <$ty as Primitive>::SIZE];
+
+            #[inline]
+            fn read_bytes<'data, R>(reader: &mut R) -> Result<Self::Bytes>
+            where
+                R: ?Sized + Read<'data>,
+            {
+                reader.read_array()
+            }
+
+            #[inline]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                $ty::from_le_bytes(bytes)
+            }
+
+            #[inline]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                $ty::from_be_bytes(bytes)
+            }
+
+            #[inline]
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                $ty::from_ne_bytes(bytes)
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> Self::Bytes {
+                $ty::to_le_bytes(self)
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> Self::Bytes {
+                $ty::to_be_bytes(self)
+            }
+
+            #[inline]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                $ty::to_ne_bytes(self)
+            }
+        }
+    )*};
+}
+
+impl_primitive!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64);
+
+/// A primitive floating-point type that can be read or written with a
+/// [`ByteOrder`].
+///
+/// This trait is sealed and cannot be implemented outside of [`zc_io`]. It is
+/// implemented for [`f32`] and [`f64`], which are converted from and to their
+/// bytes through their bit patterns, so every value, including NaNs with a
+/// payload, is preserved exactly.
+///
+/// [`zc_io`]: crate
+pub trait Float: Primitive {}
+
+impl Float for f32 {}
+
+impl Float for f64 {}
+
+/// A value whose byte order is part of its type, such as [`Le<T>`] or
+/// [`Be<T>`].
+///
+/// This trait is sealed and cannot be implemented outside of [`zc_io`]. It is
+/// what [`ReadExt::read_wrapped`] and [`WriteExt::write_wrapped`] are generic
+/// over.
+///
+/// [`zc_io`]: crate
+/// [`ReadExt::read_wrapped`]: crate::ReadExt::read_wrapped
+/// [`WriteExt::write_wrapped`]: crate::WriteExt::write_wrapped
+pub trait Wrapped: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>;
+
+    #[doc(hidden)]
+    fn write_to<W>(self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write;
+}
+
+macro_rules! impl_wrapped {
+    ($($name:ident, $order:ident, $endian:literal;)*) => {$(
+        #[doc = concat!("A [`Primitive`] `T` stored in ", $endian, "-endian byte order.")]
+        ///
+        /// Declaring a field with this type, rather than `T`, makes its byte
+        /// order part of the type, so it cannot be read or written in the
+        /// wrong one by accident. It converts from and to `T` with [`From`].
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T>
+        where
+            T: Primitive,
+        {
+            #[doc = concat!("Reads a ", $endian, "-endian `T` from `reader`.")]
+            ///
+            /// # Errors
+            ///
+            /// This method returns the same errors as [`read_array()`].
+            ///
+            /// [`read_array()`]: Read::read_array
+            #[inline]
+            pub fn read<'data, R>(reader: &mut R) -> Result<Self>
+            where
+                R: ?Sized + Read<'data>,
+            {
+                reader.read_int::<T, $order>().map($name)
+            }
+
+            #[doc = concat!("Writes this value to `writer` in ", $endian, "-endian byte order.")]
+            ///
+            /// # Errors
+            ///
+            /// This method returns the same errors as [`write_all()`].
+            ///
+            /// [`write_all()`]: Write::write_all
+            #[inline]
+            pub fn write<W>(self, writer: &mut W) -> Result<()>
+            where
+                W: ?Sized + Write,
+            {
+                writer.write_int::<T, $order>(self.0)
+            }
+        }
+
+        impl<T> private::Sealed for $name<T> where T: Primitive {}
+
+        impl<T> Wrapped for $name<T>
+        where
+            T: Primitive,
+        {
+            #[inline]
+            fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+            where
+                R: ?Sized + Read<'data>,
+            {
+                Self::read(reader)
+            }
+
+            #[inline]
+            fn write_to<W>(self, writer: &mut W) -> Result<()>
+            where
+                W: ?Sized + Write,
+            {
+                self.write(writer)
+            }
+        }
+
+        impl<T> From<T> for $name<T>
+        where
+            T: Primitive,
+        {
+            #[inline]
+            fn from(value: T) -> Self {
+                $name(value)
+            }
+        }
+    )*};
+}
+
+impl_wrapped! {
+    Le, LittleEndian, "little";
+    Be, BigEndian, "big";
+}
+
+macro_rules! impl_from_wrapped {
+    ($($ty:ident)*) => {$(
+        impl From<Le<$ty>> for $ty {
+            #[inline]
+            fn from(value: Le<$ty>) -> Self {
+                value.0
+            }
+        }
+
+        impl From<Be<$ty>> for $ty {
+            #[inline]
+            fn from(value: Be<$ty>) -> Self {
+                value.0
+            }
+        }
+    )*};
+}
+
+impl_from_wrapped!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64);
@@ -0,0 +1,178 @@
+use crate::{Read, Result, Write};
+
+use alloc::{borrow::Cow, vec};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+/// The `BytesReader<B>` struct implements [`Read<'data>`] for any
+/// [`bytes::Buf`], such as [`bytes::Bytes`].
+///
+/// A [`Buf`] owns its data, or shares ownership of it, so the bytes it holds
+/// never live for `'data`. As a result, [`read_slice`] always returns an
+/// [`Owned`] value. To avoid copying, use [`read_bytes`] instead, which
+/// returns a [`Bytes`] handle. When `B` is [`Bytes`] or [`BytesMut`], that
+/// handle shares the underlying memory instead of copying it.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use zc_io::{BytesReader, Read};
+///
+/// let mut reader = BytesReader::new(Bytes::from_static(b"\x05hello world"));
+/// let len = reader.read_next()?;
+///
+/// let hello = reader.read_bytes(len.into())?;
+/// assert_eq!(hello, "hello");
+/// assert_eq!(reader.get_ref().as_ref(), b" world");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+/// [`read_bytes`]: BytesReader::read_bytes
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+#[derive(Debug)]
+pub struct BytesReader<B> {
+    inner: B,
+}
+
+impl<B> BytesReader<B>
+where
+    B: Buf,
+{
+    /// Creates a new `BytesReader<B>` from some buffer.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: B) -> Self {
+        BytesReader { inner }
+    }
+
+    /// Reads `len` bytes from this reader as a [`Bytes`] handle.
+    ///
+    /// This uses [`Buf::copy_to_bytes`], which doesn't copy when `B` is
+    /// [`Bytes`] or [`BytesMut`].
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if fewer than `len`
+    /// bytes remain in this reader. In this case, no bytes are consumed.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> Result<Bytes> {
+        if self.inner.remaining() < len {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        Ok(self.inner.copy_to_bytes(len))
+    }
+
+    /// Gets a reference to the underlying buffer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying buffer.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Unwraps the `BytesReader<B>`, returning the underlying buffer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<'data, B> Read<'data> for BytesReader<B>
+where
+    B: Buf,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        if !self.inner.has_remaining() {
+            return Err(error!(UnexpectedEof, "failed to read byte"));
+        }
+
+        Ok(self.inner.get_u8())
+    }
+
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if self.inner.remaining() < len {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = vec![0; len];
+        self.inner.copy_to_slice(&mut buf);
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.inner.remaining() < N {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        let mut array = [0; N];
+        self.inner.copy_to_slice(&mut array);
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.inner.remaining() < buf.len() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        self.inner.copy_to_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.inner.remaining() < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        self.inner.advance(n);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.inner.remaining())
+    }
+}
+
+/// Write is implemented for [`BytesMut`] by appending to the buffer. The
+/// buffer will grow as needed.
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+impl Write for BytesMut {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> Result<usize> {
+        self.put_slice(data);
+        Ok(data.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_all(&mut self, data: &[u8]) -> Result<()> {
+        self.put_slice(data);
+        Ok(())
+    }
+}
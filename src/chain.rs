@@ -0,0 +1,201 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::cmp;
+
+/// A reader adapter which reads from one reader until it is exhausted, and
+/// then from another.
+///
+/// This struct is generally created by calling [`chain`] on a reader. Please
+/// see the documentation of [`chain`] for more details.
+///
+/// [`chain`]: crate::ReadExt::chain
+#[derive(Debug)]
+pub struct Chain<T, U> {
+    first: T,
+    second: U,
+    done_first: bool,
+}
+
+impl<T, U> Chain<T, U> {
+    #[inline]
+    pub(crate) fn new(first: T, second: U) -> Self {
+        Chain {
+            first,
+            second,
+            done_first: false,
+        }
+    }
+
+    /// Gets references to the underlying readers in this `Chain<T, U>`.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> (&T, &U) {
+        (&self.first, &self.second)
+    }
+
+    /// Gets mutable references to the underlying readers in this
+    /// `Chain<T, U>`.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying readers as doing so may corrupt the internal state of this
+    /// `Chain<T, U>`.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> (&mut T, &mut U) {
+        (&mut self.first, &mut self.second)
+    }
+
+    /// Unwraps the `Chain<T, U>`, returning the underlying readers.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> (T, U) {
+        (self.first, self.second)
+    }
+}
+
+impl<'data, T, U> Chain<T, U>
+where
+    T: Read<'data>,
+    U: Read<'data>,
+{
+    /// Reads the next byte from the first reader, returning `None` once it is
+    /// exhausted.
+    ///
+    /// Reading a single byte either fully succeeds or consumes nothing, which
+    /// is what makes it safe to probe for the end of the first reader.
+    #[inline]
+    fn next_from_first(&mut self) -> Result<Option<u8>> {
+        if self.done_first {
+            return Ok(None);
+        }
+
+        match self.first.read_next() {
+            Ok(byte) => Ok(Some(byte)),
+            Err(error) if error.is_eof() => {
+                self.done_first = true;
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns how many of the next `len` bytes are known to be available in
+    /// the first reader.
+    #[inline]
+    fn available_in_first(&self, len: usize) -> usize {
+        if self.done_first {
+            return 0;
+        }
+
+        self.first
+            .remaining_hint()
+            .map_or(0, |hint| cmp::min(hint, len))
+    }
+
+    /// Fills as much of `buf` as possible from the first reader, returning the
+    /// number of bytes that were filled.
+    #[inline]
+    fn fill_from_first(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let known = self.available_in_first(buf.len());
+        self.first.read_exact_into(&mut buf[..known])?;
+
+        for (filled, slot) in buf.iter_mut().enumerate().skip(known) {
+            match self.next_from_first()? {
+                Some(byte) => *slot = byte,
+                None => return Ok(filled),
+            }
+        }
+        Ok(buf.len())
+    }
+}
+
+impl<'data, T, U> Read<'data> for Chain<T, U>
+where
+    T: Read<'data>,
+    U: Read<'data>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        match self.next_from_first()? {
+            Some(byte) => Ok(byte),
+            None => self.second.read_next(),
+        }
+    }
+
+    /// Reads `len` bytes, first from the first reader and then from the second.
+    ///
+    /// If either reader can serve the whole request by itself, this forwards
+    /// to it and borrows whenever it does. For the first reader, that requires
+    /// its [`remaining_hint`] to cover `len` bytes, and the second reader
+    /// serves the request once the first is exhausted. Otherwise, a request
+    /// straddles both readers, and the bytes are gathered into an [`Owned`]
+    /// value, since a borrowed slice cannot span two sources.
+    ///
+    /// [`remaining_hint`]: Read::remaining_hint
+    /// [`Owned`]: Cow::Owned
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if self.done_first {
+            return self.second.read_slice(len);
+        }
+
+        let known = self.available_in_first(len);
+        if known == len {
+            return self.first.read_slice(len);
+        }
+
+        let mut buf = self.first.read_slice(known)?.into_owned();
+        while buf.len() < len {
+            if let Some(byte) = self.next_from_first()? {
+                buf.push(byte);
+            } else if buf.is_empty() {
+                // The first reader was exhausted exactly, so nothing has to
+                // be gathered.
+                return self.second.read_slice(len);
+            } else {
+                let rest = self.second.read_slice(len - buf.len())?;
+                buf.extend_from_slice(&rest);
+                break;
+            }
+        }
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        let filled = self.fill_from_first(buf)?;
+        self.second.read_exact_into(&mut buf[filled..])
+    }
+
+    #[inline]
+    fn skip(&mut self, mut n: usize) -> Result<()> {
+        let known = self.available_in_first(n);
+        self.first.skip(known)?;
+        n -= known;
+
+        while n > 0 && self.next_from_first()?.is_some() {
+            n -= 1;
+        }
+        self.second.skip(n)
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        let second = self.second.remaining_hint()?;
+        if self.done_first {
+            return Some(second);
+        }
+
+        self.first.remaining_hint()?.checked_add(second)
+    }
+}
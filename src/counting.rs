@@ -0,0 +1,353 @@
+use crate::{align, IoSlice, Peek, Read, ReadRef, Result, SliceReader, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A reader adapter which counts the bytes read from an underlying reader.
+///
+/// This is useful for progress reporting, or for tracking offsets when the
+/// underlying reader, such as an [`IoReader<R>`], has no notion of position.
+/// Only bytes that were actually consumed are counted, so a failed read does
+/// not contribute to [`count`].
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{CountingReader, Read};
+///
+/// let mut reader = CountingReader::new(&b"\x01\x02abcdef"[..]);
+/// reader.read_next()?;
+/// reader.read_array::<1>()?;
+/// assert_eq!(reader.read_array()?, *b"abcdef");
+/// assert_eq!(reader.count(), 8);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`IoReader<R>`]: crate::IoReader
+/// [`count`]: CountingReader::count
+#[derive(Debug)]
+pub struct CountingReader<R> {
+    inner: R,
+    count: u64,
+}
+
+impl<R> CountingReader<R> {
+    /// Creates a new `CountingReader<R>` wrapping `inner`, with a count of
+    /// `0`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: R) -> Self {
+        CountingReader { inner, count: 0 }
+    }
+
+    /// Returns the number of bytes read since this reader was created or its
+    /// count was last reset.
+    #[must_use]
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Resets the number of bytes read to `0`.
+    #[inline]
+    pub fn reset_count(&mut self) {
+        self.count = 0;
+    }
+
+    /// Skips past the padding up to the next count that is a multiple of
+    /// `boundary`.
+    ///
+    /// Alignment is relative to where counting started, which makes this
+    /// useful for formats that align their fields to the start of a stream,
+    /// even if the underlying reader has no notion of position. Nothing is
+    /// skipped if the count is already aligned.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `boundary` is not a
+    /// power of two.
+    ///
+    /// Otherwise, this method returns the same errors as [`skip()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{CountingReader, Read};
+    ///
+    /// let mut reader = CountingReader::new(&b"\x01\0\0\0\x02\0"[..]);
+    /// assert_eq!(reader.read_next()?, 0x01);
+    /// reader.align_to(4)?;
+    /// assert_eq!(reader.count(), 4);
+    /// assert_eq!(reader.read_next()?, 0x02);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    /// [`skip()`]: Read::skip
+    #[inline]
+    pub fn align_to<'data>(&mut self, boundary: usize) -> Result<()>
+    where
+        R: Read<'data>,
+    {
+        let padding = align::padding(self.count, boundary)?;
+        self.skip(padding)
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader are not counted.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `CountingReader<R>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    #[inline]
+    fn add(&mut self, n: usize) {
+        self.count += n as u64;
+    }
+}
+
+impl<'data, R> Read<'data> for CountingReader<R>
+where
+    R: Read<'data>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        let byte = self.inner.read_next()?;
+        self.add(1);
+        Ok(byte)
+    }
+
+    /// Reads a slice from the underlying reader, counting the length of the
+    /// returned slice rather than `len`, since the two are allowed to differ.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        let slice = self.inner.read_slice(len)?;
+        self.add(slice.len());
+        Ok(slice)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let array = self.inner.read_array()?;
+        self.add(N);
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact_into(buf)?;
+        self.add(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.inner.skip(n)?;
+        self.add(n);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        self.inner.remaining_hint()
+    }
+
+    /// Reads a C string from the underlying reader, counting its terminating
+    /// NUL byte as well.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        let cstr = self.inner.read_cstr()?;
+        self.add(cstr.len() + 1);
+        Ok(cstr)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        let slice = self.inner.read_until(delim)?;
+        self.add(slice.len());
+        Ok(slice)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        let buf = self.inner.read_to_end()?;
+        self.add(buf.len());
+        Ok(buf)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        let slice = self.inner.read_remaining()?;
+        self.add(slice.len());
+        Ok(slice)
+    }
+}
+
+impl<'data, R> Peek<'data> for CountingReader<R>
+where
+    R: Peek<'data>,
+{
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.inner.peek_array()
+    }
+}
+
+impl<'data, R> ReadRef<'data> for CountingReader<R>
+where
+    R: ReadRef<'data>,
+{
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        let array = self.inner.read_array_ref()?;
+        self.add(N);
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        let sub = self.inner.read_sub(n)?;
+        self.add(n);
+        Ok(sub)
+    }
+}
+
+/// A writer adapter which counts the bytes written to an underlying writer.
+///
+/// Only bytes that the underlying writer accepted are counted: [`write`] adds
+/// the number of bytes it reports, and [`write_all`] adds the length of the
+/// whole buffer once it succeeds.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{CountingWriter, Write};
+///
+/// let mut buf = [0; 8];
+/// let mut writer = CountingWriter::new(&mut buf[..]);
+///
+/// assert_eq!(writer.write(b"abc")?, 3);
+/// writer.write_all(b"def")?;
+/// writer.flush()?;
+/// assert_eq!(writer.write(b"ghijkl")?, 2);
+/// assert!(writer.write_all(b"m").is_err());
+///
+/// assert_eq!(writer.count(), 8);
+/// assert_eq!(&buf, b"abcdefgh");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`write`]: Write::write
+/// [`write_all`]: Write::write_all
+#[derive(Debug)]
+pub struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W> CountingWriter<W> {
+    /// Creates a new `CountingWriter<W>` wrapping `inner`, with a count of
+    /// `0`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        CountingWriter { inner, count: 0 }
+    }
+
+    /// Returns the number of bytes written since this writer was created or
+    /// its count was last reset.
+    #[must_use]
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Resets the number of bytes written to `0`.
+    #[inline]
+    pub fn reset_count(&mut self) {
+        self.count = 0;
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Bytes written directly to the underlying writer are not counted.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the `CountingWriter<W>`, returning the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Write for CountingWriter<W>
+where
+    W: Write,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+
+    /// Writes the entire buffer to the underlying writer with its own
+    /// [`write_all`], counting the buffer once it has been written.
+    ///
+    /// If an error occurs, the bytes that were partially written are not
+    /// counted, since the underlying writer doesn't report how many there
+    /// were.
+    ///
+    /// [`write_all`]: Write::write_all
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.inner.write_all(buf)?;
+        self.count += buf.len() as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        let n = self.inner.write_vectored(bufs)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+}
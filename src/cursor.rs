@@ -0,0 +1,447 @@
+use crate::{align, BufRead, Peek, Read, ReadRef, Result, SliceReader, Write};
+
+use core::cmp;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A `Cursor<T>` wraps an in-memory buffer and tracks a position within it.
+///
+/// This is the zero-copy analogue of [`std::io::Cursor`]. Unlike reading from
+/// a `&[u8]` directly, a `Cursor<T>` remembers where it started, which makes
+/// it possible to report byte offsets, for example in parse errors.
+///
+/// Whether a `Cursor<T>` supports zero-copy reads depends on `T`:
+///
+/// * `Cursor<&'data T>`, where `T: AsRef<[u8]>`, implements
+///   [`Read<'data>`] by borrowing from the underlying buffer. The borrowed
+///   slices live for `'data`, not for the borrow of the cursor itself.
+/// * `Cursor<Vec<u8>>` owns its buffer, so it implements [`Read<'data>`] by
+///   copying, meaning that [`read_slice`] will always return an [`Owned`]
+///   value.
+///
+/// A `Cursor<T>` is also a writer when `T` is a `Vec<u8>` or a `&mut [u8]`.
+/// Writes start at the current position, overwriting existing bytes, and
+/// advance it by the amount written. A `Cursor<Vec<u8>>` grows its buffer when
+/// writing past the end, whereas a `Cursor<&mut [u8]>` writes as much as fits.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Cursor, Read, ReadRef};
+///
+/// let data = b"\x03abcdef".to_vec();
+/// let mut cursor = Cursor::new(&data);
+///
+/// let len = cursor.read_next()?;
+/// let slice = cursor.read_sub(len.into())?.remaining();
+/// assert_eq!(slice, b"abc");
+/// assert_eq!(cursor.position(), 4);
+///
+/// cursor.set_position(1);
+/// assert_eq!(cursor.read_array()?, *b"ab");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// Writing a placeholder, and overwriting it once the body is written:
+///
+/// ```
+/// use zc_io::{Cursor, Write};
+///
+/// let mut buf = [0; 5];
+/// let mut cursor = Cursor::new(&mut buf[..]);
+/// cursor.write_all(b"\0body")?;
+/// cursor.set_position(0);
+/// cursor.write_all(b"\x04")?;
+/// assert_eq!(cursor.position(), 1);
+/// assert_eq!(buf, *b"\x04body");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`std::io::Cursor`]: https://doc.rust-lang.org/std/io/struct.Cursor.html
+/// [`Read<'data>`]: Read
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cursor<T> {
+    inner: T,
+    pos: usize,
+}
+
+impl<T> Cursor<T> {
+    /// Creates a new `Cursor<T>` wrapping the provided buffer.
+    ///
+    /// The initial position of the cursor is `0`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Cursor { inner, pos: 0 }
+    }
+
+    /// Returns the current position of this cursor.
+    #[must_use]
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the position of this cursor.
+    ///
+    /// The position may be set beyond the end of the buffer, in which case
+    /// any subsequent read will report end-of-file.
+    #[inline]
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Skips past the padding up to the next position that is a multiple of
+    /// `boundary`.
+    ///
+    /// Nothing is skipped if the position is already aligned.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `boundary` is not a
+    /// power of two.
+    ///
+    /// Otherwise, this method returns the same errors as [`skip()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Cursor, Read};
+    ///
+    /// let data = b"\x01\0\0\0\x02\0\0\0";
+    /// let mut cursor = Cursor::new(data);
+    /// cursor.read_next()?;
+    /// cursor.align_to(4)?;
+    /// assert_eq!(cursor.position(), 4);
+    /// cursor.align_to(4)?;
+    /// assert_eq!(cursor.read_next()?, 0x02);
+    /// assert!(cursor.align_to(3).is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    /// [`skip()`]: Read::skip
+    #[inline]
+    pub fn align_to<'data>(&mut self, boundary: usize) -> Result<()>
+    where
+        Self: Read<'data>,
+    {
+        let padding = align::padding(self.pos as u64, boundary)?;
+        self.skip(padding)
+    }
+
+    /// Gets a reference to the underlying buffer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying buffer.
+    ///
+    /// Care should be taken to avoid modifying the buffer in a way that
+    /// invalidates the position of this cursor.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the `Cursor<T>`, returning the underlying buffer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Runs `f` on the bytes of `data` after `pos`, then moves `pos` past however
+/// many bytes `f` consumed.
+#[inline]
+fn advance<'a, U, F>(data: &'a [u8], pos: &mut usize, f: F) -> Result<U>
+where
+    F: FnOnce(&mut &'a [u8]) -> Result<U>,
+{
+    let mut rest = data.get(*pos..).unwrap_or_default();
+    let len = rest.len();
+    let value = f(&mut rest)?;
+    *pos += len - rest.len();
+    Ok(value)
+}
+
+impl<'data, T> Read<'data> for Cursor<&'data T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        advance(T::as_ref(self.inner), &mut self.pos, Read::read_next)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        advance(T::as_ref(self.inner), &mut self.pos, |rest| {
+            rest.read_slice(len)
+        })
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        advance(T::as_ref(self.inner), &mut self.pos, Read::read_array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        advance(T::as_ref(self.inner), &mut self.pos, |rest| {
+            rest.read_exact_into(buf)
+        })
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        advance(T::as_ref(self.inner), &mut self.pos, |rest| rest.skip(n))
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(T::as_ref(self.inner).len().saturating_sub(self.pos))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        advance(T::as_ref(self.inner), &mut self.pos, Read::read_cstr)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        advance(T::as_ref(self.inner), &mut self.pos, |rest| {
+            rest.read_until(delim)
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        advance(T::as_ref(self.inner), &mut self.pos, Read::read_remaining)
+    }
+}
+
+impl<'data, T> Peek<'data> for Cursor<&'data T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut pos = self.pos;
+        advance(T::as_ref(self.inner), &mut pos, Read::read_array)
+    }
+}
+
+impl<'data, T> BufRead<'data> for Cursor<&'data T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(T::as_ref(self.inner).get(self.pos..).unwrap_or_default())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        let len = T::as_ref(self.inner).len();
+        self.pos += cmp::min(amt, len.saturating_sub(self.pos));
+    }
+}
+
+impl<'data, T> ReadRef<'data> for Cursor<&'data T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        advance(
+            T::as_ref(self.inner),
+            &mut self.pos,
+            ReadRef::read_array_ref,
+        )
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        advance(T::as_ref(self.inner), &mut self.pos, |rest| {
+            rest.read_sub(n)
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data> Read<'data> for Cursor<Vec<u8>> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        advance(&self.inner, &mut self.pos, Read::read_next)
+    }
+
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        advance(&self.inner, &mut self.pos, |rest| {
+            let slice = rest.read_slice(len)?;
+            Ok(Cow::Owned(slice.into_owned()))
+        })
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        advance(&self.inner, &mut self.pos, Read::read_array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        advance(&self.inner, &mut self.pos, |rest| rest.read_exact_into(buf))
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        advance(&self.inner, &mut self.pos, |rest| rest.skip(n))
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.inner.len().saturating_sub(self.pos))
+    }
+
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        advance(&self.inner, &mut self.pos, |rest| {
+            let cstr = rest.read_cstr()?;
+            Ok(Cow::Owned(cstr.into_owned()))
+        })
+    }
+
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        advance(&self.inner, &mut self.pos, |rest| {
+            let slice = rest.read_until(delim)?;
+            Ok(Cow::Owned(slice.into_owned()))
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl Peek<'_> for Cursor<Vec<u8>> {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut pos = self.pos;
+        advance(&self.inner, &mut pos, Read::read_array)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl BufRead<'_> for Cursor<Vec<u8>> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(self.inner.get(self.pos..).unwrap_or_default())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos += cmp::min(amt, self.inner.len().saturating_sub(self.pos));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl Cursor<Vec<u8>> {
+    /// Overwrites the bytes at `offset` with `buf`, without changing the
+    /// position of this cursor.
+    ///
+    /// This is meant for back-patching, such as filling in a length or a
+    /// checksum once the data it covers has been written. Unlike writing, it
+    /// never grows the buffer, so it can't leave a gap of zeros by accident.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `buf` doesn't fit
+    /// within the buffer at `offset`. In this case, nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Cursor, WriteExt};
+    ///
+    /// let mut cursor = Cursor::new(Vec::new());
+    /// cursor.write_u16_be(0)?;
+    /// cursor.write_str("body")?;
+    ///
+    /// let len = cursor.position() as u16 - 2;
+    /// cursor.write_all_at(0, &len.to_be_bytes())?;
+    /// assert_eq!(cursor.position(), 6);
+    /// assert_eq!(cursor.get_ref(), b"\x00\x04body");
+    ///
+    /// assert!(cursor.write_all_at(5, b"yy").is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    #[inline]
+    pub fn write_all_at(&mut self, offset: u64, buf: &[u8]) -> Result<()> {
+        let target = usize::try_from(offset)
+            .ok()
+            .and_then(|start| self.inner.get_mut(start..)?.get_mut(..buf.len()))
+            .ok_or_else(|| error!(InvalidInput, "write extends past the end of the buffer"))?;
+
+        target.copy_from_slice(buf);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl Write for Cursor<Vec<u8>> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if self.pos > self.inner.len() {
+            self.inner.resize(self.pos, 0);
+        }
+
+        let overlap = cmp::min(self.inner.len() - self.pos, buf.len());
+        let (head, tail) = buf.split_at(overlap);
+        self.inner[self.pos..self.pos + overlap].copy_from_slice(head);
+        self.inner.extend_from_slice(tail);
+        self.pos += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Write for Cursor<&mut [u8]> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let rest = self.inner.get_mut(self.pos..).unwrap_or_default();
+        let n = cmp::min(rest.len(), buf.len());
+        rest[..n].copy_from_slice(&buf[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
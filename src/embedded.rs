@@ -0,0 +1,199 @@
+use crate::{Error, ErrorKind, Read, Result, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec};
+use embedded_io::ReadExactError;
+
+/// Converts an [`embedded_io::Error`] into an [`Error`] of the corresponding
+/// [`ErrorKind`].
+fn convert<E>(error: &E) -> Error
+where
+    E: embedded_io::Error,
+{
+    use embedded_io::ErrorKind as Kind;
+
+    let kind = match error.kind() {
+        Kind::NotFound => ErrorKind::NotFound,
+        Kind::PermissionDenied => ErrorKind::PermissionDenied,
+        Kind::ConnectionRefused => ErrorKind::ConnectionRefused,
+        Kind::ConnectionReset => ErrorKind::ConnectionReset,
+        Kind::ConnectionAborted => ErrorKind::ConnectionAborted,
+        Kind::NotConnected => ErrorKind::NotConnected,
+        Kind::AddrInUse => ErrorKind::AddrInUse,
+        Kind::AddrNotAvailable => ErrorKind::AddrNotAvailable,
+        Kind::BrokenPipe => ErrorKind::BrokenPipe,
+        Kind::AlreadyExists => ErrorKind::AlreadyExists,
+        Kind::InvalidInput => ErrorKind::InvalidInput,
+        Kind::InvalidData => ErrorKind::InvalidData,
+        Kind::TimedOut => ErrorKind::TimedOut,
+        Kind::Interrupted => ErrorKind::Interrupted,
+        Kind::Unsupported => ErrorKind::Unsupported,
+        Kind::OutOfMemory => ErrorKind::OutOfMemory,
+        Kind::WriteZero => ErrorKind::WriteZero,
+        _ => ErrorKind::Other,
+    };
+    Error::from(kind)
+}
+
+/// The `EmbeddedIoReader<R>` struct implements [`Read<'data>`] to any
+/// [`embedded_io::Read`].
+///
+/// Like [`IoReader<R>`], an `EmbeddedIoReader<R>` will never support zero-copy
+/// operations, meaning that [`read_slice`] will always return an [`Owned`]
+/// value.
+///
+/// Errors of the underlying reader are converted by their
+/// [`embedded_io::ErrorKind`], which is mapped to the [`ErrorKind`] of the
+/// same name, or to [`ErrorKind::Other`] if there is none.
+///
+/// [`Read<'data>`]: Read
+/// [`IoReader<R>`]: crate::IoReader
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[cfg_attr(doc_cfg, doc(cfg(feature = "embedded-io")))]
+#[derive(Debug)]
+pub struct EmbeddedIoReader<R> {
+    inner: R,
+}
+
+impl<R> EmbeddedIoReader<R>
+where
+    R: embedded_io::Read,
+{
+    /// Creates a new `EmbeddedIoReader<R>` from some reader.
+    #[must_use]
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        EmbeddedIoReader { inner: reader }
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `EmbeddedIoReader<R>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg_attr(not(feature = "alloc"), allow(clippy::elidable_lifetime_names))]
+impl<'data, R> Read<'data> for EmbeddedIoReader<R>
+where
+    R: embedded_io::Read,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        let [byte] = self.read_array()?;
+        Ok(byte)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = vec![0; len];
+        self.read_exact_into(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        match self.inner.read_exact(buf) {
+            Ok(()) => Ok(()),
+            Err(ReadExactError::UnexpectedEof) => {
+                Err(error!(UnexpectedEof, "failed to fill whole buffer"))
+            }
+            Err(ReadExactError::Other(error)) => Err(convert(&error)),
+        }
+    }
+}
+
+/// The `EmbeddedIoWriter<W>` struct implements [`Write`] to any
+/// [`embedded_io::Write`].
+///
+/// Errors of the underlying writer are converted like they are for an
+/// [`EmbeddedIoReader<R>`]. Unlike [`embedded_io::Write::write_all`], which
+/// panics if the writer stops accepting bytes, [`write_all`] returns an
+/// [`ErrorKind::WriteZero`] error in that case.
+///
+/// [`write_all`]: Write::write_all
+#[cfg_attr(doc_cfg, doc(cfg(feature = "embedded-io")))]
+#[derive(Debug)]
+pub struct EmbeddedIoWriter<W> {
+    inner: W,
+}
+
+impl<W> EmbeddedIoWriter<W>
+where
+    W: embedded_io::Write,
+{
+    /// Creates a new `EmbeddedIoWriter<W>` from some writer.
+    #[must_use]
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        EmbeddedIoWriter { inner: writer }
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the `EmbeddedIoWriter<W>`, returning the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Write for EmbeddedIoWriter<W>
+where
+    W: embedded_io::Write,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.inner.write(buf).map_err(|error| convert(&error))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush().map_err(|error| convert(&error))
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{BufRead, Peek, Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+/// A reader which is always at end-of-file.
+///
+/// This struct is generally created by calling [`empty`]. Please see the
+/// documentation of [`empty`] for more details.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Empty {
+    _private: (),
+}
+
+/// Creates a reader that is always at end-of-file.
+///
+/// Every read of at least one byte from the returned [`Empty`] fails with an
+/// [`ErrorKind::UnexpectedEof`] error, while reading zero bytes succeeds:
+/// [`read_slice(0)`] returns an empty [`Borrowed`] slice, and
+/// [`read_array::<0>()`] returns an empty array.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{ErrorKind, Read};
+///
+/// let mut reader = zc_io::empty();
+/// assert_eq!(reader.read_array::<0>()?, []);
+/// reader.read_exact_into(&mut [])?;
+///
+/// assert_eq!(reader.read_next().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+/// assert_eq!(reader.read_array::<1>().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// With `alloc`, the same goes for [`read_slice`]:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::borrow::Cow;
+/// use zc_io::{ErrorKind, Read};
+///
+/// let mut reader = zc_io::empty();
+/// assert!(matches!(reader.read_slice(0)?, Cow::Borrowed([])));
+/// assert_eq!(reader.read_slice(1).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+/// # }
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+/// [`read_slice(0)`]: Read::read_slice
+/// [`read_slice`]: Read::read_slice
+/// [`Borrowed`]: Cow::Borrowed
+/// [`read_array::<0>()`]: Read::read_array
+#[must_use]
+#[inline]
+pub const fn empty() -> Empty {
+    Empty { _private: () }
+}
+
+impl Read<'_> for Empty {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        Err(error!(UnexpectedEof, "failed to read byte"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'static, [u8]>> {
+        if len > 0 {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        Ok(Cow::Borrowed(&[]))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if N > 0 {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        Ok([0; N])
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if !buf.is_empty() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if n > 0 {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+impl Peek<'_> for Empty {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.read_array()
+    }
+}
+
+impl BufRead<'_> for Empty {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(&[])
+    }
+
+    #[inline]
+    fn consume(&mut self, _: usize) {}
+}
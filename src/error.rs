@@ -1,8 +1,12 @@
 #![allow(clippy::module_name_repetitions)]
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{borrow::Cow, string::String};
 use core::{
+    array::TryFromSliceError,
     fmt::{self, Formatter},
     result,
+    str::Utf8Error,
 };
 
 #[cfg(feature = "std")]
@@ -14,9 +18,105 @@ use std::{
 
 /// A convenient alias for [`io::ErrorKind`].
 #[cfg(feature = "std")]
-#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 pub type ErrorKind = io::ErrorKind;
 
+/// A list specifying general categories of I/O error.
+///
+/// This list is intended to grow over time and it is not recommended to
+/// exhaustively match against it. When `std` is enabled, this is an alias for
+/// `std::io::ErrorKind` instead, whose variants are a superset of these.
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An entity was not found, often a file.
+    NotFound,
+    /// The operation lacked the necessary privileges to complete.
+    PermissionDenied,
+    /// The connection was refused by the remote server.
+    ConnectionRefused,
+    /// The connection was reset by the remote server.
+    ConnectionReset,
+    /// The connection was aborted (terminated) by the remote server.
+    ConnectionAborted,
+    /// The network operation failed because it was not connected yet.
+    NotConnected,
+    /// A socket address could not be bound because the address is already in
+    /// use elsewhere.
+    AddrInUse,
+    /// A nonexistent interface was requested or the requested address was not
+    /// local.
+    AddrNotAvailable,
+    /// The operation failed because a pipe was closed.
+    BrokenPipe,
+    /// An entity already exists, often a file.
+    AlreadyExists,
+    /// The operation needs to block to complete, but the blocking operation
+    /// was requested to not occur.
+    WouldBlock,
+    /// A parameter was incorrect.
+    InvalidInput,
+    /// Data not valid for the operation were encountered.
+    InvalidData,
+    /// The I/O operation's timeout expired, causing it to be canceled.
+    TimedOut,
+    /// An error returned when an operation could not be completed because a
+    /// call to [`write`] returned [`Ok(0)`].
+    ///
+    /// [`write`]: crate::Write::write
+    /// [`Ok(0)`]: Ok
+    WriteZero,
+    /// This operation was interrupted.
+    ///
+    /// Interrupted operations can typically be retried.
+    Interrupted,
+    /// This operation is unsupported on this platform.
+    Unsupported,
+    /// An error returned when an operation could not be completed because an
+    /// "end of file" was reached prematurely.
+    UnexpectedEof,
+    /// An operation could not be completed, because it failed to allocate
+    /// enough memory.
+    OutOfMemory,
+    /// A custom error that does not fall under any other I/O error kind.
+    Other,
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrorKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::NotFound => "entity not found",
+            ErrorKind::PermissionDenied => "permission denied",
+            ErrorKind::ConnectionRefused => "connection refused",
+            ErrorKind::ConnectionReset => "connection reset",
+            ErrorKind::ConnectionAborted => "connection aborted",
+            ErrorKind::NotConnected => "not connected",
+            ErrorKind::AddrInUse => "address in use",
+            ErrorKind::AddrNotAvailable => "address not available",
+            ErrorKind::BrokenPipe => "broken pipe",
+            ErrorKind::AlreadyExists => "entity already exists",
+            ErrorKind::WouldBlock => "operation would block",
+            ErrorKind::InvalidInput => "invalid input parameter",
+            ErrorKind::InvalidData => "invalid data",
+            ErrorKind::TimedOut => "timed out",
+            ErrorKind::WriteZero => "write zero",
+            ErrorKind::Interrupted => "operation interrupted",
+            ErrorKind::Unsupported => "unsupported",
+            ErrorKind::UnexpectedEof => "unexpected end of file",
+            ErrorKind::OutOfMemory => "out of memory",
+            ErrorKind::Other => "other error",
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// A specialized [`Result`] type for [`zc_io`].
 ///
 /// This type is used across [`zc_io`] for any operation which may produce an
@@ -41,8 +141,8 @@ pub type Result<T> = result::Result<T, Error>;
 /// traits.
 ///
 /// When `std` is enabled this becomes a zero-cost abstraction over
-/// [`io::Error`]. In `no_std` environments, error messages are preserved while
-/// [`io::ErrorKind`] information is stripped.
+/// [`io::Error`]. In `no_std` environments, an error consists of an
+/// [`ErrorKind`] and a message, which is static unless `alloc` is enabled.
 ///
 /// If you are working in an environment that may be `no_std`, and you need to
 /// create an [`Error`] yourself, use the [`error!`] macro.
@@ -54,38 +154,64 @@ pub struct Error {
     #[cfg(feature = "std")]
     inner: io::Error,
     #[cfg(not(feature = "std"))]
-    pub(crate) inner: &'static str,
+    kind: ErrorKind,
+    #[cfg(not(feature = "std"))]
+    inner: Message,
 }
 
-/// Constructs a new [`Error`] from an [`io::ErrorKind`] variant identifier and
-/// a [`&'static str`].
+/// The message of an [`Error`] in a `no_std` environment.
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+type Message = Cow<'static, str>;
+
+/// The message of an [`Error`] in a `no_std` environment.
+#[cfg(not(feature = "alloc"))]
+type Message = &'static str;
+
+/// Constructs a new [`Error`] from an [`ErrorKind`] variant identifier and
+/// either a [`&'static str`] or a format string with its arguments.
 ///
-/// In a `std` environment, this macro delegates to [`Error::new`]; in a
-/// `no_std` environment, the error kind is elided but the corresponding
-/// message is preserved.
+/// In a `std` environment, this macro delegates to [`Error::new`]. In a
+/// `no_std` environment, an error with a static message is created without
+/// allocating, while a formatted message is allocated as a [`String`].
+/// Without `alloc`, nothing can be allocated, so the arguments are evaluated
+/// but the format string itself is used verbatim as the message.
+///
+/// A message without any arguments is used verbatim, so it can't capture
+/// variables implicitly: `error!(Other, "{x}")` results in the message
+/// `{x}`. Pass the variable as an argument instead, like
+/// `error!(Other, "{}", x)`.
 ///
 /// [`&'static str`]: prim@str
+/// [`String`]: alloc::string::String
 ///
 /// # Examples
 ///
-/// In a `std` environment:
-///
 /// ```
 /// use zc_io::error;
 ///
 /// let my_error = error!(Other, "miscellaneous user error");
 ///
-/// // ErrorKind inspection only works when `std` is available:
-/// #[cfg(feature = "std")]
 /// assert_eq!(my_error.kind(), zc_io::ErrorKind::Other);
 ///
 /// assert_eq!(my_error.to_string(), "miscellaneous user error");
+///
+/// let tag = 0x7f;
+/// let my_error = error!(InvalidData, "unknown tag {:#04x} at offset {}", tag, 42);
+///
+/// if cfg!(feature = "alloc") {
+///     assert_eq!(my_error.to_string(), "unknown tag 0x7f at offset 42");
+/// } else {
+///     assert_eq!(my_error.to_string(), "unknown tag {:#04x} at offset {}");
+/// }
 /// ```
 #[macro_export]
 macro_rules! error {
     ($variant:ident, $message:literal) => {
         $crate::__error_impl!($variant, $message)
     };
+    ($variant:ident, $format:literal, $($arg:tt)+) => {
+        $crate::__error_impl!($variant, $format, $($arg)+)
+    };
 }
 
 #[cfg(feature = "std")]
@@ -95,23 +221,62 @@ macro_rules! __error_impl {
     ($variant:ident, $message:literal) => {
         $crate::Error::new($crate::ErrorKind::$variant, $message)
     };
+    ($variant:ident, $format:literal, $($arg:tt)+) => {
+        $crate::Error::new(
+            $crate::ErrorKind::$variant,
+            $crate::__private::format!($format, $($arg)+),
+        )
+    };
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __error_impl {
+    ($variant:ident, $message:literal) => {
+        $crate::Error::__const_error($crate::ErrorKind::$variant, $message)
+    };
+    ($variant:ident, $format:literal, $($arg:tt)+) => {
+        $crate::Error::__formatted_error(
+            $crate::ErrorKind::$variant,
+            $crate::__private::format!($format, $($arg)+),
+        )
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __error_impl {
     ($variant:ident, $message:literal) => {
-        $crate::Error::__const_error($message)
+        $crate::Error::__const_error($crate::ErrorKind::$variant, $message)
     };
+    ($variant:ident, $format:literal, $($arg:tt)+) => {{
+        let _ = ::core::format_args!($format, $($arg)+);
+        $crate::Error::__const_error($crate::ErrorKind::$variant, $format)
+    }};
 }
 
 #[cfg(not(feature = "std"))]
 impl Error {
     #[doc(hidden)]
     #[must_use]
-    pub const fn __const_error(message: &'static str) -> Error {
-        Error { inner: message }
+    pub const fn __const_error(kind: ErrorKind, message: &'static str) -> Error {
+        #[cfg(feature = "alloc")]
+        let inner = Cow::Borrowed(message);
+        #[cfg(not(feature = "alloc"))]
+        let inner = message;
+        Error { kind, inner }
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn __formatted_error(kind: ErrorKind, message: String) -> Error {
+        Error {
+            kind,
+            inner: Cow::Owned(message),
+        }
     }
 }
 
@@ -205,22 +370,249 @@ impl Error {
     pub fn into_inner(self) -> Option<Box<dyn error::Error + Send + Sync>> {
         self.inner.into_inner()
     }
+}
 
+impl Error {
     /// Returns the corresponding [`ErrorKind`] for this error.
     ///
-    /// For more information, refer to [`io::Error::kind`].
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    /// With `std`, refer to [`io::Error::kind`] for more information.
+    #[cfg(feature = "std")]
     #[must_use]
     #[inline]
     pub fn kind(&self) -> ErrorKind {
         self.inner.kind()
     }
+
+    /// Returns the corresponding [`ErrorKind`] for this error.
+    #[cfg(not(feature = "std"))]
+    #[must_use]
+    #[inline]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Error {
+    /// Creates an [`ErrorKind::Other`] error with a static `message`.
+    ///
+    /// This is available with and without `std`, and never allocates in a
+    /// `no_std` environment, which makes it the function equivalent of
+    /// [`error!`] for code that already holds a [`&'static str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Error, ErrorKind};
+    ///
+    /// let error = Error::from_static("checksum mismatch");
+    /// assert_eq!(error.kind(), ErrorKind::Other);
+    /// assert_eq!(error.to_string(), "checksum mismatch");
+    /// ```
+    ///
+    /// [`error!`]: crate::error!
+    /// [`&'static str`]: prim@str
+    #[must_use]
+    #[inline]
+    pub fn from_static(message: &'static str) -> Error {
+        #[cfg(feature = "std")]
+        return Error::new(ErrorKind::Other, message);
+        #[cfg(not(feature = "std"))]
+        return Error::__const_error(ErrorKind::Other, message);
+    }
+
+    /// Returns whether this error signals that a reader reached end-of-file,
+    /// which is when its kind is [`ErrorKind::UnexpectedEof`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"a";
+    /// reader.read_next()?;
+    /// assert!(reader.read_next().unwrap_err().is_eof());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_eof(&self) -> bool {
+        self.kind() == ErrorKind::UnexpectedEof
+    }
+
+    /// Returns whether this error signals that an operation was interrupted,
+    /// which is when its kind is [`ErrorKind::Interrupted`].
+    ///
+    /// Such operations can typically be retried.
+    #[must_use]
+    #[inline]
+    pub fn is_interrupted(&self) -> bool {
+        self.kind() == ErrorKind::Interrupted
+    }
+
+    /// Creates an [`ErrorKind::InvalidData`] error for a tag that was read at
+    /// `offset`, but isn't recognized.
+    ///
+    /// Using this instead of a custom message keeps the wording consistent
+    /// for every tagged union, such as when reading them with
+    /// [`ReadExt::read_tag`]. Without `alloc`, the message can't be formatted,
+    /// so it is just "unknown tag", without `tag` and `offset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Error, ErrorKind};
+    ///
+    /// let error = Error::unknown_tag(0x7, 128);
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    ///
+    /// if cfg!(feature = "alloc") {
+    ///     assert_eq!(error.to_string(), "unknown tag 0x07 at offset 128");
+    /// } else {
+    ///     assert_eq!(error.to_string(), "unknown tag");
+    /// }
+    /// ```
+    ///
+    /// [`ReadExt::read_tag`]: crate::ReadExt::read_tag
+    #[must_use]
+    #[inline]
+    pub fn unknown_tag(tag: u8, offset: u64) -> Error {
+        #[cfg(feature = "alloc")]
+        return error!(InvalidData, "unknown tag {:#04x} at offset {}", tag, offset);
+        #[cfg(not(feature = "alloc"))]
+        return {
+            let _ = (tag, offset);
+            error!(InvalidData, "unknown tag")
+        };
+    }
 }
 
+impl Error {
+    /// Creates an [`ErrorKind::InvalidData`] error describing where `error`
+    /// found invalid UTF-8.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn invalid_utf8(error: Utf8Error) -> Error {
+        error!(
+            InvalidData,
+            "invalid UTF-8 sequence after {} valid bytes",
+            error.valid_up_to()
+        )
+    }
+}
+
+/// Clones an [`Error`].
+///
+/// Without `std`, the kind and the message are copied. With `std`,
+/// [`io::Error`] can't be cloned, so an equivalent error is created instead:
+/// OS errors and errors created from a bare [`ErrorKind`] are reproduced
+/// exactly, while every other error is recreated from its kind and its
+/// message. This includes errors with a static message, such as the ones
+/// [`std::io::Read::read_exact`] returns, and errors with a payload, whose
+/// clone keeps the message of the payload, but not the payload itself. As a
+/// result, [`get_ref`] on the clone returns an error that only carries the
+/// message, and the [`source`] of the original payload is lost.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::error;
+///
+/// let error = error!(InvalidData, "bad magic");
+/// let clone = error.clone();
+/// assert_eq!(clone.to_string(), error.to_string());
+/// ```
+///
+/// The message of an error returned by [`IoReader<R>`] is kept as well:
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use zc_io::{IoReader, Read};
+///
+/// let mut reader = IoReader::new(&b"a"[..]);
+/// let error = reader.read_array::<2>().unwrap_err();
+/// let clone = error.clone();
+/// assert_eq!(clone.to_string(), "failed to fill whole buffer");
+/// assert_eq!(clone, error);
+/// # }
+/// ```
+///
+/// [`std::io::Read::read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+/// [`IoReader<R>`]: crate::IoReader
+///
+/// [`get_ref`]: Error::get_ref
+/// [`source`]: error::Error::source
+impl Clone for Error {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn clone(&self) -> Self {
+        #[cfg(feature = "alloc")]
+        let inner = self.inner.clone();
+        #[cfg(not(feature = "alloc"))]
+        let inner = self.inner;
+        Error {
+            kind: self.kind,
+            inner,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn clone(&self) -> Self {
+        if let Some(code) = self.raw_os_error() {
+            return Error::from_raw_os_error(code);
+        }
+
+        if let Some(error) = self.get_ref() {
+            return Error::new(self.kind(), error.to_string());
+        }
+
+        // Errors with a static message don't expose it other than through
+        // `Display`, which is also how a bare kind is told apart.
+        let bare = Error::from(self.kind());
+        let message = self.to_string();
+        if message == bare.to_string() {
+            return bare;
+        }
+        Error::new(self.kind(), message)
+    }
+}
+
+/// Compares two [`Error`]s by their kind and message.
+///
+/// With `std`, two errors are equal if they have the same [`ErrorKind`] and
+/// the same [`Display`] output, regardless of their payloads otherwise. This
+/// makes it possible to compare an error against one created with
+/// [`error!`]:
+///
+/// ```
+/// use zc_io::{error, Read};
+///
+/// let mut reader: &[u8] = &[];
+/// assert_eq!(reader.read_next().unwrap_err(), error!(UnexpectedEof, "failed to read byte"));
+/// ```
+///
+/// Without `std`, the kinds and the messages are compared.
+///
+/// [`Display`]: fmt::Display
+/// [`error!`]: crate::error!
+impl PartialEq for Error {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.inner == other.inner
+    }
+
+    #[cfg(feature = "std")]
+    fn eq(&self, other: &Self) -> bool {
+        self.kind() == other.kind() && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Error {}
+
 impl fmt::Debug for Error {
     #[cfg(not(feature = "std"))]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Error")
+            .field("kind", &self.kind)
             .field("message", &self.inner)
             .finish()
     }
@@ -234,7 +626,7 @@ impl fmt::Debug for Error {
 impl fmt::Display for Error {
     #[cfg(not(feature = "std"))]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str(self.inner)
+        fmt::Display::fmt(&self.inner, f)
     }
 
     #[cfg(feature = "std")]
@@ -271,15 +663,72 @@ impl From<Error> for io::Error {
     }
 }
 
-#[cfg(feature = "std")]
-#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+/// Creates an [`Error`] from an [`ErrorKind`], with a message describing
+/// the kind.
 impl From<ErrorKind> for Error {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn from(kind: ErrorKind) -> Self {
+        Error::__const_error(kind, kind.as_str())
+    }
+
+    #[cfg(feature = "std")]
     #[inline]
     fn from(kind: ErrorKind) -> Self {
         io::Error::from(kind).into()
     }
 }
 
+/// Converts a [`Utf8Error`] into an [`ErrorKind::InvalidData`] error.
+///
+/// With `std`, the [`Utf8Error`] is kept as the payload of the error.
+/// Otherwise, the error only carries a static message.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{ErrorKind, Read};
+///
+/// fn read_name(reader: &mut &[u8]) -> zc_io::Result<String> {
+///     let bytes = reader.read_array::<4>()?;
+///     Ok(std::str::from_utf8(&bytes)?.to_owned())
+/// }
+///
+/// let mut reader: &[u8] = b"ab\xffd";
+/// assert_eq!(read_name(&mut reader).unwrap_err().kind(), ErrorKind::InvalidData);
+/// ```
+impl From<Utf8Error> for Error {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn from(_: Utf8Error) -> Self {
+        error!(InvalidData, "invalid UTF-8 sequence")
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn from(error: Utf8Error) -> Self {
+        Error::new(ErrorKind::InvalidData, error)
+    }
+}
+
+/// Converts a [`TryFromSliceError`] into an [`ErrorKind::InvalidData`] error.
+///
+/// With `std`, the [`TryFromSliceError`] is kept as the payload of the error.
+/// Otherwise, the error only carries a static message.
+impl From<TryFromSliceError> for Error {
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn from(_: TryFromSliceError) -> Self {
+        error!(InvalidData, "could not convert slice to array")
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn from(error: TryFromSliceError) -> Self {
+        Error::new(ErrorKind::InvalidData, error)
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<W> From<IntoInnerError<W>> for Error {
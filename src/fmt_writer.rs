@@ -0,0 +1,87 @@
+use crate::{Error, Result, Write};
+
+use core::fmt;
+
+/// An adapter that implements [`fmt::Write`] for any [`Write`].
+///
+/// This makes it possible to redirect anything that formats into a
+/// [`fmt::Write`] into a writer of this crate. A [`fmt::Error`] cannot carry
+/// any information, so the first error of the underlying writer is stored
+/// instead, and can be recovered with [`into_result`] once formatting is done.
+/// After an error, any further writes fail without touching the underlying
+/// writer.
+///
+/// This is also how [`Write::write_fmt`] is implemented.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write as _;
+/// use zc_io::FmtWriter;
+///
+/// let mut buf = [0; 4];
+/// let mut output = &mut buf[..];
+/// let mut writer = FmtWriter::new(&mut output);
+/// assert!(writer.write_str("overflow").is_err());
+///
+/// let error = writer.into_result().unwrap_err();
+/// assert_eq!(error.kind(), zc_io::ErrorKind::WriteZero);
+/// ```
+///
+/// [`into_result`]: FmtWriter::into_result
+#[derive(Debug)]
+pub struct FmtWriter<'a, W>
+where
+    W: ?Sized,
+{
+    inner: &'a mut W,
+    error: Option<Error>,
+}
+
+impl<'a, W> FmtWriter<'a, W>
+where
+    W: ?Sized + Write,
+{
+    /// Creates a new `FmtWriter<'a, W>` that writes into `inner`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: &'a mut W) -> Self {
+        FmtWriter { inner, error: None }
+    }
+
+    /// Consumes the `FmtWriter<'a, W>`, returning the first error of the
+    /// underlying writer, if any.
+    ///
+    /// # Errors
+    ///
+    /// The first error that [`write_all`] returned while formatting is
+    /// returned.
+    ///
+    /// [`write_all`]: Write::write_all
+    #[inline]
+    pub fn into_result(self) -> Result<()> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W> fmt::Write for FmtWriter<'_, W>
+where
+    W: ?Sized + Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+
+        match self.inner.write_all(s.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                self.error = Some(error);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
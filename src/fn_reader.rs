@@ -0,0 +1,129 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec};
+use core::{fmt, slice};
+
+/// A reader which fills buffers by calling a closure.
+///
+/// This struct is generally created by calling [`reader_fn`]. Please see the
+/// documentation of [`reader_fn`] for more details.
+#[derive(Clone, Copy)]
+pub struct FnReader<F> {
+    f: F,
+}
+
+impl<F> FnReader<F> {
+    /// Creates a new `FnReader<F>` calling `f` to fill buffers.
+    #[must_use]
+    #[inline]
+    pub fn new(f: F) -> Self {
+        FnReader { f }
+    }
+
+    /// Unwraps the `FnReader<F>`, returning the underlying closure.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+/// Creates a reader which fills buffers by calling `f`.
+///
+/// `f` behaves like [`std::io::Read::read`]: it fills a prefix of the buffer
+/// it is given and returns the length of that prefix, where `0` signals
+/// end-of-file. Reads call `f` for as long as they need more bytes, retrying
+/// [`ErrorKind::Interrupted`] errors, which makes this well suited for mock
+/// readers that simulate partial reads, interruptions, and end-of-file.
+///
+/// Since the bytes are copied into buffers, the returned reader never
+/// supports zero-copy operations, meaning that [`read_slice`] will always
+/// return an [`Owned`] value.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Error, ErrorKind, Read};
+///
+/// let mut calls = 0;
+/// let mut reader = zc_io::reader_fn(|buf: &mut [u8]| {
+///     calls += 1;
+///     match calls {
+///         1 => Err(Error::from(ErrorKind::Interrupted)),
+///         2 | 3 => {
+///             buf[0] = b'a';
+///             Ok(1)
+///         }
+///         _ => Ok(0),
+///     }
+/// });
+///
+/// assert_eq!(reader.read_array()?, *b"aa");
+/// assert!(reader.read_next().unwrap_err().is_eof());
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`std::io::Read::read`]: https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
+/// [`ErrorKind::Interrupted`]: crate::ErrorKind::Interrupted
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[must_use]
+#[inline]
+pub fn reader_fn<F>(f: F) -> FnReader<F>
+where
+    F: FnMut(&mut [u8]) -> Result<usize>,
+{
+    FnReader::new(f)
+}
+
+impl<F> fmt::Debug for FnReader<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnReader").finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(not(feature = "alloc"), allow(clippy::elidable_lifetime_names))]
+impl<'data, F> Read<'data> for FnReader<F>
+where
+    F: FnMut(&mut [u8]) -> Result<usize>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        let mut byte = 0;
+        self.read_exact_into(slice::from_mut(&mut byte))?;
+        Ok(byte)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = vec![0; len];
+        self.read_exact_into(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, mut buf: &mut [u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match (self.f)(buf) {
+                Ok(0) => return Err(error!(UnexpectedEof, "failed to fill whole buffer")),
+                Ok(n) => buf = &mut buf[n..],
+                Err(error) if error.is_interrupted() => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+}
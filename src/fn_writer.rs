@@ -0,0 +1,85 @@
+use crate::{Result, Write};
+
+use core::fmt;
+
+/// A writer which passes every write to a closure.
+///
+/// This struct is generally created by calling [`writer_fn`]. Please see the
+/// documentation of [`writer_fn`] for more details.
+#[derive(Clone, Copy)]
+pub struct FnWriter<F> {
+    f: F,
+}
+
+impl<F> FnWriter<F> {
+    /// Creates a new `FnWriter<F>` calling `f` for every write.
+    #[must_use]
+    #[inline]
+    pub fn new(f: F) -> Self {
+        FnWriter { f }
+    }
+
+    /// Unwraps the `FnWriter<F>`, returning the underlying closure.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+/// Creates a writer whose [`write`] calls `f`, and whose [`flush`] does
+/// nothing.
+///
+/// `f` has the same contract as [`write`]: it returns how many of the bytes
+/// it was given were written, which may be fewer than all of them. This is
+/// handy to adapt foreign APIs, or to write test doubles that inspect every
+/// write.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Write, WriteExt};
+///
+/// let mut writes = Vec::new();
+/// let mut writer = zc_io::writer_fn(|buf: &[u8]| {
+///     writes.push(buf.to_vec());
+///     Ok(buf.len())
+/// });
+///
+/// writer.write_all(b"abc")?;
+/// writer.write_u16_be(0x0102)?;
+/// assert_eq!(writes, [&b"abc"[..], &[0x01, 0x02]]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`write`]: Write::write
+/// [`flush`]: Write::flush
+#[must_use]
+#[inline]
+pub fn writer_fn<F>(f: F) -> FnWriter<F>
+where
+    F: FnMut(&[u8]) -> Result<usize>,
+{
+    FnWriter::new(f)
+}
+
+impl<F> fmt::Debug for FnWriter<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnWriter").finish_non_exhaustive()
+    }
+}
+
+impl<F> Write for FnWriter<F>
+where
+    F: FnMut(&[u8]) -> Result<usize>,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        (self.f)(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
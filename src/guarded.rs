@@ -0,0 +1,173 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+/// A reader adapter which rejects reads of untrusted lengths above a cap.
+///
+/// Binary formats often prefix a payload with its length, and a malicious
+/// prefix can make a reader such as [`IoReader<R>`] try to allocate gigabytes
+/// in [`read_slice`]. A `GuardedReader<R>` checks every [`read_slice`] and
+/// [`skip`] request against its [`max_alloc`] before forwarding it, so an
+/// oversized request fails without allocating or consuming anything.
+///
+/// [`read_array`] is not guarded, as its length is a compile-time constant.
+/// Neither are reads that stop at a delimiter, such as [`read_until`], since
+/// their length is not known upfront.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{GuardedReader, Read, ReadExt};
+///
+/// let mut reader = GuardedReader::new(&b"\xff\xff\xff\xff\x0fpayload"[..], 1024);
+///
+/// let len = reader.read_uleb128()?;
+/// assert!(reader.skip(len as usize).is_err());
+/// assert_eq!(reader.read_array()?, *b"pay");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`IoReader<R>`]: crate::IoReader
+/// [`read_slice`]: Read::read_slice
+/// [`skip`]: Read::skip
+/// [`max_alloc`]: GuardedReader::max_alloc
+/// [`read_array`]: Read::read_array
+/// [`read_until`]: Read::read_until
+#[derive(Debug)]
+pub struct GuardedReader<R> {
+    inner: R,
+    max_alloc: usize,
+}
+
+impl<R> GuardedReader<R> {
+    /// Creates a new `GuardedReader<R>` which rejects [`read_slice`] and
+    /// [`skip`] requests for more than `max_alloc` bytes.
+    ///
+    /// [`read_slice`]: Read::read_slice
+    /// [`skip`]: Read::skip
+    #[must_use]
+    #[inline]
+    pub fn new(inner: R, max_alloc: usize) -> Self {
+        GuardedReader { inner, max_alloc }
+    }
+
+    /// Returns the largest number of bytes a single request may ask for.
+    #[must_use]
+    #[inline]
+    pub fn max_alloc(&self) -> usize {
+        self.max_alloc
+    }
+
+    /// Sets the largest number of bytes a single request may ask for.
+    #[inline]
+    pub fn set_max_alloc(&mut self, max_alloc: usize) {
+        self.max_alloc = max_alloc;
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reads made directly on the underlying reader are not guarded.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `GuardedReader<R>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns an error if a request for `len` bytes exceeds the cap.
+    #[inline]
+    fn check(&self, len: usize) -> Result<()> {
+        if len > self.max_alloc {
+            return Err(error!(InvalidData, "length exceeds limit"));
+        }
+        Ok(())
+    }
+}
+
+impl<'data, R> Read<'data> for GuardedReader<R>
+where
+    R: Read<'data>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.inner.read_next()
+    }
+
+    /// Reads `n` bytes from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if `n` exceeds
+    /// [`max_alloc`], in which case nothing is read.
+    ///
+    /// Otherwise, this method returns the same errors as the underlying
+    /// reader.
+    ///
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`max_alloc`]: GuardedReader::max_alloc
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, n: usize) -> Result<Cow<'data, [u8]>> {
+        self.check(n)?;
+        self.inner.read_slice(n)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.inner.read_array()
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact_into(buf)
+    }
+
+    /// Skips `n` bytes of the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if `n` exceeds
+    /// [`max_alloc`], in which case nothing is skipped.
+    ///
+    /// Otherwise, this method returns the same errors as the underlying
+    /// reader.
+    ///
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`max_alloc`]: GuardedReader::max_alloc
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.check(n)?;
+        self.inner.skip(n)
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        self.inner.remaining_hint()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_cstr()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_until(delim)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{Read, Result, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec};
+use core::cmp;
+use heapless::{Deque, Vec};
+
+/// Read is implemented for [`Deque<u8, N>`] by draining bytes from the front
+/// of the queue.
+///
+/// The bytes are owned by the queue, so [`read_slice`] always returns an
+/// [`Owned`] value.
+///
+/// # Examples
+///
+/// ```
+/// use heapless::Deque;
+/// use zc_io::Read;
+///
+/// let mut queue: Deque<u8, 8> = Deque::new();
+/// for byte in *b"abcd" {
+///     queue.push_back(byte).unwrap();
+/// }
+///
+/// assert_eq!(queue.read_array()?, *b"ab");
+/// assert_eq!(queue.read_next()?, b'c');
+/// assert_eq!(queue.len(), 1);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Deque<u8, N>`]: Deque
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heapless")))]
+#[cfg_attr(not(feature = "alloc"), allow(clippy::elidable_lifetime_names))]
+impl<'data, const N: usize> Read<'data> for Deque<u8, N> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.pop_front()
+            .ok_or_else(|| error!(UnexpectedEof, "failed to read byte"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = vec![0; len];
+        self.read_exact_into(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const M: usize>(&mut self) -> Result<[u8; M]> {
+        let mut array = [0; M];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.len() < buf.len() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        let (front, back) = self.as_slices();
+        let split = cmp::min(front.len(), buf.len());
+        let (head, tail) = buf.split_at_mut(split);
+        head.copy_from_slice(&front[..split]);
+        tail.copy_from_slice(&back[..tail.len()]);
+        self.skip(buf.len())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.len() < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        for _ in 0..n {
+            self.pop_front();
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Write is implemented for [`Vec<u8, N>`] by appending to the vector until
+/// it is full.
+///
+/// Once the capacity is exhausted, write operations return short writes:
+/// ultimately, `Ok(0)`; in this situation, [`write_all`] returns an error of
+/// kind [`ErrorKind::WriteZero`], just like it does for `&mut [u8]`.
+///
+/// # Examples
+///
+/// ```
+/// use heapless::Vec;
+/// use zc_io::{ErrorKind, Write};
+///
+/// let mut buf: Vec<u8, 4> = Vec::new();
+/// buf.write_all(b"ab")?;
+///
+/// let error = buf.write_all(b"cde").unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::WriteZero);
+/// assert_eq!(buf, *b"abcd");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Vec<u8, N>`]: Vec
+/// [`write_all`]: Write::write_all
+/// [`ErrorKind::WriteZero`]: crate::ErrorKind::WriteZero
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heapless")))]
+impl<const N: usize> Write for Vec<u8, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let amount = cmp::min(buf.len(), self.capacity() - self.len());
+        // The capacity was checked above, so this can't fail.
+        let _ = self.extend_from_slice(&buf[..amount]);
+        Ok(amount)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        if self.write(buf)? == buf.len() {
+            Ok(())
+        } else {
+            Err(error!(WriteZero, "failed to write whole buffer"))
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(not(feature = "std"))]
+use core::{mem, ops::Deref};
+#[cfg(feature = "std")]
+use std::io;
+
+/// A convenient alias for [`io::IoSlice`].
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub type IoSlice<'a> = io::IoSlice<'a>;
+
+/// A buffer type used with [`Write::write_vectored`].
+///
+/// It dereferences to the `&[u8]` it wraps. When `std` is enabled, this is an
+/// alias for `std::io::IoSlice` instead, which has the same interface.
+///
+/// [`Write::write_vectored`]: crate::Write::write_vectored
+#[cfg(not(feature = "std"))]
+#[derive(Copy, Clone, Debug)]
+pub struct IoSlice<'a>(&'a [u8]);
+
+#[cfg(not(feature = "std"))]
+impl<'a> IoSlice<'a> {
+    /// Creates a new `IoSlice<'a>` wrapping a byte slice.
+    #[must_use]
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        IoSlice(buf)
+    }
+
+    /// Advances the internal cursor of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics when trying to advance beyond the end of the slice.
+    #[inline]
+    pub fn advance(&mut self, n: usize) {
+        assert!(n <= self.0.len(), "advancing IoSlice beyond its length");
+        self.0 = &self.0[n..];
+    }
+
+    /// Advances a slice of slices.
+    ///
+    /// Shrinks the slice to remove any `IoSlice<'a>`s that are fully advanced
+    /// over. If the cursor ends up in the middle of an `IoSlice<'a>`, it is
+    /// modified to start at that cursor.
+    ///
+    /// # Panics
+    ///
+    /// Panics when trying to advance beyond the end of the slices.
+    #[allow(clippy::mut_mut)]
+    #[inline]
+    pub fn advance_slices(bufs: &mut &mut [IoSlice<'a>], n: usize) {
+        let mut remove = 0;
+        let mut left = n;
+        for buf in bufs.iter() {
+            if let Some(remainder) = left.checked_sub(buf.len()) {
+                left = remainder;
+                remove += 1;
+            } else {
+                break;
+            }
+        }
+
+        *bufs = &mut mem::take(bufs)[remove..];
+        if bufs.is_empty() {
+            assert!(left == 0, "advancing io slices beyond their length");
+        } else {
+            bufs[0].advance(left);
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Deref for IoSlice<'_> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
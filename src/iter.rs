@@ -0,0 +1,136 @@
+use crate::{Peek, Read, Result};
+
+use core::{fmt, iter::FusedIterator};
+
+/// An iterator over the bytes of a reader.
+///
+/// This struct is generally created by calling [`bytes`] on a reader. Please
+/// see the documentation of [`bytes`] for more details.
+///
+/// [`bytes`]: crate::ReadExt::bytes
+#[derive(Debug)]
+pub struct Bytes<R> {
+    inner: R,
+    done: bool,
+}
+
+impl<R> Bytes<R> {
+    #[inline]
+    pub(crate) fn new(inner: R) -> Self {
+        Bytes { inner, done: false }
+    }
+}
+
+impl<'data, R> Iterator for Bytes<R>
+where
+    R: Read<'data>,
+{
+    type Item = Result<u8>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<u8>> {
+        if self.done {
+            return None;
+        }
+
+        match self.inner.read_next() {
+            Ok(byte) => Some(Ok(byte)),
+            Err(error) => {
+                self.done = true;
+                if error.is_eof() {
+                    None
+                } else {
+                    Some(Err(error))
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        (self.inner.remaining_hint().unwrap_or(0), None)
+    }
+}
+
+impl<'data, R> FusedIterator for Bytes<R> where R: Read<'data> {}
+
+/// An iterator over the records of a reader, parsed by a closure.
+///
+/// This struct is generally created by calling [`records`] on a reader. Please
+/// see the documentation of [`records`] for more details.
+///
+/// [`records`]: crate::ReadExt::records
+pub struct Records<R, F> {
+    inner: R,
+    f: F,
+    done: bool,
+}
+
+impl<R, F> Records<R, F> {
+    #[inline]
+    pub(crate) fn new(inner: R, f: F) -> Self {
+        Records {
+            inner,
+            f,
+            done: false,
+        }
+    }
+
+    /// Unwraps the `Records<R, F>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R, F> fmt::Debug for Records<R, F>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Records")
+            .field("inner", &self.inner)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'data, R, F, T> Iterator for Records<R, F>
+where
+    R: Peek<'data>,
+    F: FnMut(&mut R) -> Result<T>,
+{
+    type Item = Result<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.done {
+            return None;
+        }
+
+        if let Err(error) = self.inner.peek_array::<1>() {
+            self.done = true;
+            return if error.is_eof() {
+                None
+            } else {
+                Some(Err(error))
+            };
+        }
+
+        let result = (self.f)(&mut self.inner);
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+impl<'data, R, F, T> FusedIterator for Records<R, F>
+where
+    R: Peek<'data>,
+    F: FnMut(&mut R) -> Result<T>,
+{
+}
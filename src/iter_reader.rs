@@ -0,0 +1,116 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A reader adapter which reads bytes from an iterator.
+///
+/// This turns any `Iterator<Item = u8>`, such as a decompressor that yields
+/// bytes one at a time, into a [`Read<'data>`], without going through
+/// [`std::io`]. The iterator returning `None` is reported as end-of-file.
+///
+/// Since the bytes are produced on demand, an `IterReader<I>` never supports
+/// zero-copy operations, meaning that [`read_slice`] will always return an
+/// [`Owned`] value.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{IterReader, Read, ReadExt};
+///
+/// let mut reader = IterReader::new((1..=4).map(|n| n * 0x11));
+/// assert_eq!(reader.read_next()?, 0x11);
+/// assert_eq!(reader.read_u16_be()?, 0x2233);
+/// assert_eq!(reader.read_array()?, [0x44]);
+/// assert!(reader.read_next().unwrap_err().is_eof());
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+/// [`std::io`]: https://doc.rust-lang.org/std/io/index.html
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[derive(Debug)]
+pub struct IterReader<I> {
+    inner: I,
+}
+
+impl<I> IterReader<I> {
+    /// Creates a new `IterReader<I>` reading the bytes yielded by `inner`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: I) -> Self {
+        IterReader { inner }
+    }
+
+    /// Gets a reference to the underlying iterator.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &I {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying iterator.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut I {
+        &mut self.inner
+    }
+
+    /// Unwraps the `IterReader<I>`, returning the underlying iterator.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+#[cfg_attr(not(feature = "alloc"), allow(clippy::elidable_lifetime_names))]
+impl<'data, I> Read<'data> for IterReader<I>
+where
+    I: Iterator<Item = u8>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.inner
+            .next()
+            .ok_or_else(|| error!(UnexpectedEof, "failed to read byte"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let buf: Vec<u8> = self.inner.by_ref().take(len).collect();
+        if buf.len() < len {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        for slot in buf {
+            *slot = self.read_next()?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if n > 0 && self.inner.nth(n - 1).is_none() {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Read, Result, Write};
+
+/// Reads an unsigned LEB128 value that must fit in `bits` bits.
+///
+/// The returned value is guaranteed to be less than `2^bits`.
+pub(crate) fn read_unsigned<'data, R>(reader: &mut R, bits: u32) -> Result<u128>
+where
+    R: ?Sized + Read<'data>,
+{
+    let (value, _) = read_groups(reader, bits, false)?;
+    Ok(value)
+}
+
+/// Reads a signed LEB128 value that must fit in `bits` bits.
+///
+/// The returned value is guaranteed to be representable as a `bits`-bit two's
+/// complement integer.
+pub(crate) fn read_signed<'data, R>(reader: &mut R, bits: u32) -> Result<i128>
+where
+    R: ?Sized + Read<'data>,
+{
+    let (value, read_bits) = read_groups(reader, bits, true)?;
+    // Sign-extend from the most significant bit that was actually encoded.
+    let shift = u128::BITS - read_bits.min(bits);
+    #[allow(clippy::cast_possible_wrap)]
+    Ok(((value << shift) as i128) >> shift)
+}
+
+/// Reads the 7-bit groups of a LEB128 value, returning the raw accumulated
+/// value along with the number of bits that were read.
+fn read_groups<'data, R>(reader: &mut R, bits: u32, signed: bool) -> Result<(u128, u32)>
+where
+    R: ?Sized + Read<'data>,
+{
+    let mut result = 0;
+    let mut shift = 0;
+    loop {
+        let byte = reader.read_next()?;
+        let group = u128::from(byte & 0x7f);
+
+        // The final group may only use the bits that are left in the target
+        // width; anything above that would be silently discarded otherwise.
+        // Signed values may fill those bits with copies of the sign bit.
+        let remaining = bits - shift;
+        if remaining < 7 {
+            let unused = group >> (remaining - u32::from(signed));
+            if unused != 0 && !(signed && unused == 0x7f >> (remaining - 1)) {
+                return Err(error!(InvalidData, "LEB128 value overflows target width"));
+            }
+        }
+
+        result |= group << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            return Ok((result, shift));
+        }
+
+        if shift >= bits {
+            return Err(error!(
+                InvalidData,
+                "LEB128 encoding exceeds maximum length"
+            ));
+        }
+    }
+}
+
+/// The maximum length of a LEB128-encoded 128-bit value.
+const MAX_LEN: usize = 19;
+
+/// Writes `value` as an unsigned LEB128 value.
+pub(crate) fn write_unsigned<W>(writer: &mut W, mut value: u128) -> Result<()>
+where
+    W: ?Sized + Write,
+{
+    let mut buf = [0; MAX_LEN];
+    let mut len = 0;
+    loop {
+        // Truncation keeps exactly the low 7 bits, which is the point.
+        #[allow(clippy::cast_possible_truncation)]
+        let group = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = group;
+            len += 1;
+            return writer.write_all(&buf[..len]);
+        }
+
+        buf[len] = group | 0x80;
+        len += 1;
+    }
+}
+
+/// Writes `value` as a signed LEB128 value.
+pub(crate) fn write_signed<W>(writer: &mut W, mut value: i128) -> Result<()>
+where
+    W: ?Sized + Write,
+{
+    let mut buf = [0; MAX_LEN];
+    let mut len = 0;
+    loop {
+        // Truncation keeps exactly the low 7 bits, which is the point.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let group = (value & 0x7f) as u8;
+        value >>= 7;
+        // Stop once the remaining bits are all copies of the sign bit that the
+        // reader will extend from this group.
+        let sign = group & 0x40 != 0;
+        if (value == 0 && !sign) || (value == -1 && sign) {
+            buf[len] = group;
+            len += 1;
+            return writer.write_all(&buf[..len]);
+        }
+
+        buf[len] = group | 0x80;
+        len += 1;
+    }
+}
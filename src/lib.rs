@@ -10,26 +10,127 @@
 // Support using zc_io without the standard library:
 #![cfg_attr(not(feature = "std"), no_std)]
 // Enable lints:
-#![deny(clippy::pedantic, missing_docs)]
+#![deny(clippy::pedantic, missing_debug_implementations, missing_docs)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[macro_use]
 mod error;
-
+mod align;
+#[cfg(feature = "async")]
+mod async_buf;
+#[cfg(feature = "std")]
+mod buf_reader;
+#[cfg(feature = "alloc")]
+mod buf_writer;
+mod byteorder;
+#[cfg(feature = "bytes")]
+mod bytes_buf;
+mod chain;
+mod counting;
+mod cursor;
+#[cfg(feature = "embedded-io")]
+mod embedded;
+mod empty;
+mod fmt_writer;
+mod fn_reader;
+mod fn_writer;
+mod guarded;
+#[cfg(feature = "heapless")]
+mod heapless_buf;
+mod io_slice;
+mod iter;
+mod iter_reader;
+mod leb128;
+mod limit;
+mod macros;
+mod map;
+mod read_ext;
+mod read_from;
+mod repeat;
+mod scoped;
+mod seek;
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub mod serde;
+mod sink;
+mod slice_reader;
+mod take;
+mod tee;
+mod write_ext;
+mod write_to;
+mod xor;
+
+#[cfg(feature = "async")]
+pub use async_buf::AsyncBufferedReader;
 #[cfg(feature = "std")]
-pub use error::ErrorKind;
-pub use error::{Error, Result};
+pub use buf_reader::BufReader;
+#[cfg(feature = "alloc")]
+pub use buf_writer::{BufWriter, IntoInnerError};
+pub use byteorder::{
+    Be, BigEndian, ByteOrder, Float, Le, LittleEndian, NativeEndian, Primitive, Wrapped,
+};
+#[cfg(feature = "bytes")]
+pub use bytes_buf::BytesReader;
+pub use chain::Chain;
+pub use counting::{CountingReader, CountingWriter};
+pub use cursor::Cursor;
+#[cfg(feature = "embedded-io")]
+pub use embedded::{EmbeddedIoReader, EmbeddedIoWriter};
+pub use empty::{empty, Empty};
+pub use error::{Error, ErrorKind, Result};
+pub use fmt_writer::FmtWriter;
+pub use fn_reader::{reader_fn, FnReader};
+pub use fn_writer::{writer_fn, FnWriter};
+pub use guarded::GuardedReader;
+pub use io_slice::IoSlice;
+pub use iter::{Bytes, Records};
+pub use iter_reader::IterReader;
+pub use limit::LimitWriter;
+pub use map::MapReader;
+pub use read_ext::ReadExt;
+pub use read_from::ReadFrom;
+pub use repeat::{repeat, Repeat};
+pub use scoped::ScopedReader;
+pub use seek::{Seek, SeekFrom};
+pub use sink::{sink, Sink};
+pub use slice_reader::SliceReader;
+pub use take::Take;
+pub use tee::TeeWriter;
+pub use write_ext::WriteExt;
+pub use write_to::WriteTo;
+pub use xor::XorReader;
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zc_io_derive::{ReadFrom, WriteTo};
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+}
 
-use alloc::{borrow::Cow, boxed::Box, vec::Vec};
-use core::{cmp, mem};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, collections::VecDeque, vec::Vec};
+use core::{cmp, fmt, mem};
 #[cfg(feature = "std")]
 use std::{
-    fmt,
-    io::{self, IoSlice, IoSliceMut, SeekFrom},
+    io::{self, IoSliceMut},
     slice,
 };
 
+/// The size of the stack buffer used by the default [`Read::skip`].
+const SKIP_CHUNK_LEN: usize = 256;
+
+/// The size of the stack buffer used by the default [`Read::copy_to`].
+const COPY_CHUNK_LEN: usize = 1024;
+
+/// The smallest scratch buffer [`IoReader<R>`] copies through in
+/// [`Read::copy_to`].
+#[cfg(feature = "std")]
+const IO_COPY_BUF_LEN: usize = 8 * 1024;
+
 /// The `Read<'data>` trait allows for reading bytes with a lifetime of `'data`
 /// from some source.
 ///
@@ -39,7 +140,14 @@ use std::{
 /// [`read_slice()`] returning a [`Cow`] (such that it might return a [`Vec`] if
 /// the bytes requested cannot be borrowed).
 ///
+/// This trait only holds the methods a reader has to provide, along with the
+/// ones a reader may override to be more efficient. Conveniences derived from
+/// them, such as reading integers or wrapping a reader in an adapter, are
+/// provided by the [`ReadExt<'data>`] extension trait instead, which is
+/// implemented for every `Read<'data>`.
+///
 /// [`read_slice()`]: Read::read_slice
+/// [`ReadExt<'data>`]: ReadExt
 pub trait Read<'data> {
     /// Reads the next byte from the source.
     ///
@@ -66,6 +174,10 @@ pub trait Read<'data> {
     /// taken when `unsafe` functions are used to access the read bytes. Callers
     /// have to ensure that no unchecked out-of-bounds accesses are possible
     /// even if the number of returned bytes are greater or less than `n`.
+    /// [`ReadExt::read_slice_exact`] performs that check.
+    ///
+    /// If `n` is zero, every reader of this crate returns an empty
+    /// [`Borrowed`] slice without touching the source or allocating.
     ///
     /// # Errors
     ///
@@ -81,6 +193,10 @@ pub trait Read<'data> {
     ///
     /// If this function returns an error, it is unspecified how many bytes got
     /// read.
+    ///
+    /// [`Borrowed`]: Cow::Borrowed
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
     fn read_slice(&mut self, n: usize) -> Result<Cow<'data, [u8]>>;
 
     /// Reads exactly `N` bytes from this reader.
@@ -88,6 +204,9 @@ pub trait Read<'data> {
     /// Note that because this method returns an array it will always copy the
     /// bytes from the source regardless if zero-copy reads are possible.
     ///
+    /// If `N` is zero, every reader of this crate returns an empty array
+    /// without touching the source.
+    ///
     /// # Errors
     ///
     /// If this function encounters an error of the kind
@@ -103,8 +222,413 @@ pub trait Read<'data> {
     /// If this function returns an error, it is unspecified how many bytes got
     /// read.
     fn read_array<const N: usize>(&mut self) -> Result<[u8; N]>;
+
+    /// Reads exactly enough bytes from this reader to fill `buf`.
+    ///
+    /// Unlike [`read_slice()`], this method writes into caller-owned storage,
+    /// so no [`Cow`] has to be inspected or copied out of. If `buf` is empty,
+    /// the source is never touched.
+    ///
+    /// The default implementation fills `buf` one byte at a time with
+    /// [`read_next()`]; implementors are encouraged to override it with a bulk
+    /// copy when possible.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`] then the error is ignored and the operation
+    /// will continue.
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if this reader
+    /// reaches end-of-file before `buf` is completely filled.
+    ///
+    /// If any other read error is encountered then this function immediately
+    /// returns.
+    ///
+    /// If this function returns an error, it is unspecified how many bytes got
+    /// read and what the contents of `buf` are.
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`read_next()`]: Read::read_next
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        for byte in buf {
+            *byte = self.read_next()?;
+        }
+        Ok(())
+    }
+
+    /// Reads exactly `N` bytes from this reader into `buf`.
+    ///
+    /// This is [`read_array()`] for callers that want to reuse their storage,
+    /// for example a buffer on the stack that is filled once per record in a
+    /// loop, without relying on the returned array being moved into place.
+    ///
+    /// The default implementation forwards to [`read_exact_into()`], which
+    /// every reader of this crate implements with a bulk copy.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_exact_into()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"abcdef";
+    /// let mut record = [0; 3];
+    ///
+    /// reader.read_array_into(&mut record)?;
+    /// assert_eq!(record, *b"abc");
+    /// reader.read_array_into(&mut record)?;
+    /// assert_eq!(record, *b"def");
+    /// assert!(reader.read_array_into(&mut record).is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_array()`]: Read::read_array
+    /// [`read_exact_into()`]: Read::read_exact_into
+    #[inline]
+    fn read_array_into<const N: usize>(&mut self, buf: &mut [u8; N]) -> Result<()> {
+        self.read_exact_into(buf)
+    }
+
+    /// Reads and discards `n` bytes from this reader.
+    ///
+    /// This is useful for skipping over padding or fields that are not of
+    /// interest without the allocation [`read_slice()`] might make.
+    ///
+    /// The default implementation reads through a small, fixed-size buffer on
+    /// the stack with [`read_exact_into()`]. In-memory readers override it to
+    /// simply advance past the skipped bytes.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`] then the error is ignored and the operation
+    /// will continue.
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if this reader
+    /// reaches end-of-file before `n` bytes were skipped.
+    ///
+    /// If any other read error is encountered then this function immediately
+    /// returns.
+    ///
+    /// If this function returns an error, it is unspecified how many bytes got
+    /// read.
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`read_exact_into()`]: Read::read_exact_into
+    fn skip(&mut self, mut n: usize) -> Result<()> {
+        let mut buf = [0; SKIP_CHUNK_LEN];
+        while n > 0 {
+            let len = cmp::min(n, buf.len());
+            self.read_exact_into(&mut buf[..len])?;
+            n -= len;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes known to remain in this reader, if any.
+    ///
+    /// This is useful for pre-sizing collections, but, like
+    /// [`Iterator::size_hint`], it is advisory only: a reader may have more
+    /// bytes available than it reports, and the hint must never be relied on
+    /// for memory safety. A reader that reports `Some(n)` must, however, have
+    /// at least `n` bytes left.
+    ///
+    /// The default implementation returns `None`. In-memory readers, such as
+    /// `&[u8]` and [`Cursor<T>`], override it with the exact number of bytes
+    /// left, while [`IoReader<R>`] cannot know how much data its source holds.
+    ///
+    /// [`Cursor<T>`]: Cursor
+    /// [`IoReader<R>`]: IoReader
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        None
+    }
+
+    /// Reads a NUL-terminated string from this reader.
+    ///
+    /// The returned bytes are everything up to, but not including, the first
+    /// `0x00` byte. The terminator itself is consumed.
+    ///
+    /// The default implementation collects bytes from [`read_next()`] into an
+    /// [`Owned`] value. In-memory readers override it to scan for the
+    /// terminator and borrow the content instead.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if this reader
+    /// reaches end-of-file before a NUL byte is found.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"foo\0bar";
+    /// assert!(matches!(reader.read_cstr()?, Cow::Borrowed(b"foo")));
+    /// assert_eq!(reader, b"bar");
+    /// assert!(reader.read_cstr().is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_next()`]: Read::read_next
+    /// [`Owned`]: Cow::Owned
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        let mut buf = Vec::new();
+        loop {
+            match self.read_next()? {
+                0 => return Ok(Cow::Owned(buf)),
+                byte => buf.push(byte),
+            }
+        }
+    }
+
+    /// Reads bytes from this reader up to and including the delimiter `delim`.
+    ///
+    /// This mirrors [`io::BufRead::read_until`]: all bytes read are returned,
+    /// including the delimiter if it was found. Unlike [`read_cstr()`],
+    /// reaching end-of-file before the delimiter is not an error; everything
+    /// that remained is returned instead, which is empty if this reader was
+    /// already at end-of-file.
+    ///
+    /// The default implementation collects bytes from [`read_next()`] into an
+    /// [`Owned`] value. In-memory readers override it to scan for the
+    /// delimiter and borrow the content instead. [`IoReader<R>`] cannot make
+    /// use of [`io::BufRead`] here, as it would need to specialize on `R`;
+    /// call [`io::BufRead::read_until`] through [`IoReader::get_mut`] when that
+    /// matters.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_next()`], except for
+    /// [`ErrorKind::UnexpectedEof`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"one\ntwo";
+    /// assert!(matches!(reader.read_until(b'\n')?, Cow::Borrowed(b"one\n")));
+    /// assert!(matches!(reader.read_until(b'\n')?, Cow::Borrowed(b"two")));
+    /// assert!(reader.read_until(b'\n')?.is_empty());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_cstr()`]: Read::read_cstr
+    /// [`read_next()`]: Read::read_next
+    /// [`Owned`]: Cow::Owned
+    /// [`IoReader<R>`]: IoReader
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        let mut buf = Vec::new();
+        loop {
+            match self.read_next() {
+                Ok(byte) => {
+                    buf.push(byte);
+                    if byte == delim {
+                        break;
+                    }
+                }
+                Err(error) if error.is_eof() => break,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(Cow::Owned(buf))
+    }
+
+    /// Reads all bytes until end-of-file, returning them as a [`Vec`].
+    ///
+    /// The default implementation reads as many bytes as [`remaining_hint()`]
+    /// reports with [`read_slice()`] at once, and falls back to
+    /// [`read_next()`] otherwise, until end-of-file is reached. In-memory
+    /// readers copy what is left in one go, and [`IoReader<R>`] forwards to
+    /// [`std::io::Read::read_to_end`].
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`] then the error is ignored and the operation
+    /// will continue.
+    ///
+    /// Reaching end-of-file is not an error. If any other read error is
+    /// encountered then this function immediately returns, and the bytes read
+    /// so far are lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"\x02rest";
+    /// reader.read_next()?;
+    /// assert_eq!(reader.read_to_end()?, b"rest");
+    /// assert_eq!(reader.read_to_end()?, b"");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`remaining_hint()`]: Read::remaining_hint
+    /// [`read_slice()`]: Read::read_slice
+    /// [`read_next()`]: Read::read_next
+    /// [`IoReader<R>`]: IoReader
+    /// [`std::io::Read::read_to_end`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_to_end
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        loop {
+            if let Some(hint @ 1..) = self.remaining_hint() {
+                buf.extend_from_slice(&self.read_slice(hint)?);
+                continue;
+            }
+
+            match self.read_next() {
+                Ok(byte) => buf.push(byte),
+                Err(error) if error.is_eof() => return Ok(buf),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Reads all bytes until end-of-file, borrowing them if possible.
+    ///
+    /// Unlike [`read_to_end()`], which always copies, in-memory readers such
+    /// as `&[u8]`, [`SliceReader<'data>`], and [`Cursor<&'data T>`] return a
+    /// [`Borrowed`] slice of everything that is left and move to the end. The
+    /// default implementation returns the result of [`read_to_end()`] as an
+    /// [`Owned`] value.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_to_end()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::Read;
+    ///
+    /// let mut reader: &[u8] = b"\x01tail";
+    /// reader.read_next()?;
+    /// assert!(matches!(reader.read_remaining()?, Cow::Borrowed(b"tail")));
+    /// assert!(reader.is_empty());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_to_end()`]: Read::read_to_end
+    /// [`SliceReader<'data>`]: SliceReader
+    /// [`Cursor<&'data T>`]: Cursor
+    /// [`Borrowed`]: Cow::Borrowed
+    /// [`Owned`]: Cow::Owned
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.read_to_end().map(Cow::Owned)
+    }
+
+    /// Copies all bytes until end-of-file into `writer`, returning how many
+    /// were copied.
+    ///
+    /// This is the counterpart of [`std::io::copy`]. The bytes are passed to
+    /// [`write_all()`] in chunks, so nothing is buffered in full: `&[u8]` and
+    /// [`SliceReader<'data>`] write everything that is left with a single
+    /// call, [`IoReader<R>`] reuses its scratch buffer, and the default
+    /// implementation copies through a small buffer on the stack, filled with
+    /// [`read_exact_into()`] as far as [`remaining_hint()`] allows, and with
+    /// [`read_next()`] otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Reaching end-of-file is not an error. If any other read or write error
+    /// is encountered then this function immediately returns, and it is
+    /// unspecified how many bytes were copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{IterReader, Read};
+    ///
+    /// let mut buf = [0; 12];
+    /// let mut writer = &mut buf[..];
+    ///
+    /// let mut reader: &[u8] = b"\x02copied";
+    /// reader.read_next()?;
+    /// assert_eq!(reader.copy_to(&mut writer)?, 6);
+    /// assert!(reader.is_empty());
+    ///
+    /// let mut reader = IterReader::new(b" twice".iter().copied());
+    /// assert_eq!(reader.copy_to(&mut writer)?, 6);
+    /// assert_eq!(buf, *b"copied twice");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`std::io::copy`]: https://doc.rust-lang.org/std/io/fn.copy.html
+    /// [`write_all()`]: Write::write_all
+    /// [`SliceReader<'data>`]: SliceReader
+    /// [`IoReader<R>`]: IoReader
+    /// [`read_exact_into()`]: Read::read_exact_into
+    /// [`remaining_hint()`]: Read::remaining_hint
+    /// [`read_next()`]: Read::read_next
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        let mut buf = [0; COPY_CHUNK_LEN];
+        let mut copied = 0;
+        loop {
+            let len = if let Some(hint @ 1..) = self.remaining_hint() {
+                let len = cmp::min(hint, buf.len());
+                self.read_exact_into(&mut buf[..len])?;
+                len
+            } else {
+                let mut len = 0;
+                while len < buf.len() {
+                    match self.read_next() {
+                        Ok(byte) => buf[len] = byte,
+                        Err(error) if error.is_eof() => break,
+                        Err(error) => return Err(error),
+                    }
+                    len += 1;
+                }
+                len
+            };
+
+            if len == 0 {
+                return Ok(copied);
+            }
+            writer.write_all(&buf[..len])?;
+            copied += len as u64;
+        }
+    }
 }
 
+/// Readers are forwarded through `&mut R`, so a reader can be passed on by
+/// mutable reference, at any depth, without dereferencing it by hand:
+///
+/// ```
+/// use zc_io::Read;
+///
+/// fn first<'data, R: Read<'data>>(mut reader: R) -> zc_io::Result<u8> {
+///     reader.read_next()
+/// }
+///
+/// let mut reader: &[u8] = b"abc";
+/// assert_eq!(first(&mut reader)?, b'a');
+/// assert_eq!(first(&mut &mut reader)?, b'b');
+/// assert_eq!(reader, b"c");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
 impl<'data, R> Read<'data> for &mut R
 where
     R: ?Sized + Read<'data>,
@@ -114,6 +638,7 @@ where
         (**self).read_next()
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
         (**self).read_slice(len)
@@ -123,8 +648,82 @@ where
     fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
         (**self).read_array()
     }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        (**self).read_exact_into(buf)
+    }
+
+    #[inline]
+    fn read_array_into<const N: usize>(&mut self, buf: &mut [u8; N]) -> Result<()> {
+        (**self).read_array_into(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        (**self).skip(n)
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        (**self).remaining_hint()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        (**self).read_cstr()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        (**self).read_until(delim)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        (**self).read_to_end()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        (**self).read_remaining()
+    }
+
+    #[inline]
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        (**self).copy_to(writer)
+    }
 }
 
+/// Readers are forwarded through [`Box<R>`] as well, so any nesting of boxes
+/// and mutable references is a reader too:
+///
+/// ```
+/// use zc_io::Read;
+///
+/// fn first<'data, R: Read<'data>>(mut reader: R) -> zc_io::Result<u8> {
+///     reader.read_next()
+/// }
+///
+/// let reader: &[u8] = b"abcd";
+/// let mut boxed = Box::new(reader);
+/// assert_eq!(first(&mut boxed)?, b'a');
+/// assert_eq!(first(Box::new(&mut boxed))?, b'b');
+/// assert_eq!(first(&mut Box::new(&mut boxed))?, b'c');
+/// assert_eq!(first(boxed)?, b'd');
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Box<R>`]: Box
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 impl<'data, R> Read<'data> for Box<R>
 where
     R: ?Sized + Read<'data>,
@@ -143,6 +742,54 @@ where
     fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
         (**self).read_array()
     }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        (**self).read_exact_into(buf)
+    }
+
+    #[inline]
+    fn read_array_into<const N: usize>(&mut self, buf: &mut [u8; N]) -> Result<()> {
+        (**self).read_array_into(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        (**self).skip(n)
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        (**self).remaining_hint()
+    }
+
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        (**self).read_cstr()
+    }
+
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        (**self).read_until(delim)
+    }
+
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        (**self).read_to_end()
+    }
+
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        (**self).read_remaining()
+    }
+
+    #[inline]
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        (**self).copy_to(writer)
+    }
 }
 
 impl<'data> Read<'data> for &'data [u8] {
@@ -156,6 +803,7 @@ impl<'data> Read<'data> for &'data [u8] {
         Err(error!(UnexpectedEof, "failed to read byte"))
     }
 
+    #[cfg(feature = "alloc")]
     #[inline]
     fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
         if self.len() < len {
@@ -168,16 +816,908 @@ impl<'data> Read<'data> for &'data [u8] {
     }
 
     #[inline]
-    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
-        if self.len() < N {
-            return Err(error!(UnexpectedEof, "failed to read array"));
-        }
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.len() < N {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        let (array, rest) = self.split_at(N);
+        *self = rest;
+        // SAFETY: a slice of bytes whose length is `N` is identical to
+        // `[u8; N]`.
+        Ok(unsafe { *array.as_ptr().cast::<[u8; N]>() })
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.len() < buf.len() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        let (data, rest) = self.split_at(buf.len());
+        buf.copy_from_slice(data);
+        *self = rest;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.len() < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        *self = &self[n..];
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        let Some(len) = self.iter().position(|&byte| byte == 0) else {
+            return Err(error!(UnexpectedEof, "failed to read C string"));
+        };
+
+        let (cstr, rest) = self.split_at(len);
+        *self = &rest[1..];
+        Ok(Cow::Borrowed(cstr))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        let len = match self.iter().position(|&byte| byte == delim) {
+            Some(pos) => pos + 1,
+            None => self.len(),
+        };
+
+        let (slice, rest) = self.split_at(len);
+        *self = rest;
+        Ok(Cow::Borrowed(slice))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        Ok(mem::take(self).to_vec())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        Ok(Cow::Borrowed(mem::take(self)))
+    }
+
+    #[inline]
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_all(self)?;
+        Ok(mem::take(self).len() as u64)
+    }
+}
+
+/// Splits the first `len` bytes off of `slice`, which must hold at least `len`
+/// bytes, and returns them for the rest of `'data`.
+#[allow(clippy::mut_mut)]
+#[inline]
+fn split_front<'data>(slice: &mut &'data mut [u8], len: usize) -> &'data [u8] {
+    let (front, rest) = mem::take(slice).split_at_mut(len);
+    *slice = rest;
+    front
+}
+
+/// Read is implemented for `&mut [u8]` by consuming bytes from the front of
+/// the slice, exactly like it is for `&[u8]`.
+///
+/// The returned borrows live for as long as the original mutable borrow, so
+/// the slice can no longer be written to through this reader once read.
+///
+/// # Examples
+///
+/// A scratch buffer can be filled with [`Write`] and then read back without
+/// copying:
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::borrow::Cow;
+/// use zc_io::{Read, Write};
+///
+/// let mut buf = [0; 8];
+/// let mut writer = &mut buf[..];
+/// writer.write_all(b"\x03abc")?;
+/// let written = 8 - writer.len();
+///
+/// let mut reader = &mut buf[..written];
+/// let len = reader.read_next()?;
+/// assert!(matches!(reader.read_slice(len.into())?, Cow::Borrowed(b"abc")));
+/// # }
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+impl<'data> Read<'data> for &'data mut [u8] {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        if self.is_empty() {
+            return Err(error!(UnexpectedEof, "failed to read byte"));
+        }
+
+        Ok(split_front(self, 1)[0])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if self.len() < len {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        Ok(Cow::Borrowed(split_front(self, len)))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.len() < N {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        let mut array = [0; N];
+        array.copy_from_slice(split_front(self, N));
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.len() < buf.len() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        buf.copy_from_slice(split_front(self, buf.len()));
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.len() < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        split_front(self, n);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        let Some(len) = self.iter().position(|&byte| byte == 0) else {
+            return Err(error!(UnexpectedEof, "failed to read C string"));
+        };
+
+        let cstr = split_front(self, len);
+        split_front(self, 1);
+        Ok(Cow::Borrowed(cstr))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        let len = match self.iter().position(|&byte| byte == delim) {
+            Some(pos) => pos + 1,
+            None => self.len(),
+        };
+
+        Ok(Cow::Borrowed(split_front(self, len)))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        Ok(mem::take(self).to_vec())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        let rest = mem::take(self);
+        Ok(Cow::Borrowed(rest))
+    }
+}
+
+/// Read is implemented for `VecDeque<u8>` by draining bytes from the front of
+/// the queue.
+///
+/// Bytes that are read are removed from the queue, so they cannot be borrowed
+/// for `'data`. As a result, [`read_slice`] always returns an [`Owned`] value,
+/// regardless of whether the requested bytes are contiguous in the queue's
+/// storage. To read from a queue without copying, read from the slice
+/// returned by [`VecDeque::make_contiguous`] instead, and then drain the bytes
+/// that were consumed.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use zc_io::{Read, Write};
+///
+/// let mut queue = VecDeque::new();
+/// queue.write_all(b"\x01\x02abc")?;
+///
+/// assert_eq!(queue.read_array()?, [1, 2]);
+/// assert_eq!(queue.read_slice(3)?.as_ref(), b"abc");
+/// assert!(queue.is_empty());
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data> Read<'data> for VecDeque<u8> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.pop_front()
+            .ok_or_else(|| error!(UnexpectedEof, "failed to read byte"))
+    }
+
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if self.len() < len {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        Ok(Cow::Owned(self.drain(..len).collect()))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.len() < N {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        let mut array = [0; N];
+        self.read_exact_into(&mut array)?;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.len() < buf.len() {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        let (front, back) = self.as_slices();
+        let split = cmp::min(front.len(), buf.len());
+        let (head, tail) = buf.split_at_mut(split);
+        head.copy_from_slice(&front[..split]);
+        tail.copy_from_slice(&back[..tail.len()]);
+        self.drain(..buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.len() < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        self.drain(..n);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        let Some(len) = self.iter().position(|&byte| byte == 0) else {
+            return Err(error!(UnexpectedEof, "failed to read C string"));
+        };
+
+        let cstr = self.drain(..len).collect();
+        self.pop_front();
+        Ok(Cow::Owned(cstr))
+    }
+
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        let len = match self.iter().position(|&byte| byte == delim) {
+            Some(pos) => pos + 1,
+            None => self.len(),
+        };
+
+        Ok(Cow::Owned(self.drain(..len).collect()))
+    }
+
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        Ok(mem::take(self).into())
+    }
+}
+
+/// Runs `f` on the bytes of `vec`, then removes however many bytes `f`
+/// consumed from its front.
+#[cfg(feature = "alloc")]
+#[inline]
+fn consume_front<U, F>(vec: &mut Vec<u8>, f: F) -> Result<U>
+where
+    F: FnOnce(&mut &[u8]) -> Result<U>,
+{
+    let mut rest = &vec[..];
+    let value = f(&mut rest)?;
+    let consumed = vec.len() - rest.len();
+    vec.drain(..consumed);
+    Ok(value)
+}
+
+/// Reading from a `Cow<'data, [u8]>` behaves differently for each variant.
+///
+/// A [`Borrowed`] value reads like a `&'data [u8]`, so [`read_slice`] and
+/// friends borrow for `'data`. An [`Owned`] value, however, owns its bytes,
+/// and anything borrowed from them couldn't outlive the `Cow<'data, [u8]>`
+/// itself, let alone the next read, which removes them. Its reads therefore
+/// return [`Owned`] copies, just like a `VecDeque<u8>`.
+///
+/// Every read from an [`Owned`] value also shifts the remaining bytes to the
+/// front of the vector. For many small reads from a large owned buffer, a
+/// [`Cursor<Vec<u8>>`] is more efficient.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use zc_io::Read;
+///
+/// let data = b"abcd";
+/// let mut borrowed = Cow::Borrowed(&data[..]);
+/// assert!(matches!(borrowed.read_slice(2)?, Cow::Borrowed(b"ab")));
+///
+/// let mut owned: Cow<'_, [u8]> = Cow::Owned(data.to_vec());
+/// assert!(matches!(owned.read_slice(2)?, Cow::Owned(bytes) if bytes == b"ab"));
+/// assert_eq!(owned.as_ref(), b"cd");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Borrowed`]: Cow::Borrowed
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+/// [`Cursor<Vec<u8>>`]: Cursor
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data> Read<'data> for Cow<'data, [u8]> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_next(),
+            Cow::Owned(vec) => consume_front(vec, |rest| rest.read_next()),
+        }
+    }
+
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_slice(len),
+            Cow::Owned(_) if len == 0 => Ok(Cow::Borrowed(&[])),
+            Cow::Owned(vec) => consume_front(vec, |rest| {
+                let slice = rest.read_slice(len)?;
+                Ok(Cow::Owned(slice.into_owned()))
+            }),
+        }
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_array(),
+            Cow::Owned(vec) => consume_front(vec, |rest| rest.read_array()),
+        }
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_exact_into(buf),
+            Cow::Owned(vec) => consume_front(vec, |rest| rest.read_exact_into(buf)),
+        }
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        match self {
+            Cow::Borrowed(slice) => slice.skip(n),
+            Cow::Owned(vec) => consume_front(vec, |rest| rest.skip(n)),
+        }
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_cstr(),
+            Cow::Owned(vec) => consume_front(vec, |rest| {
+                let cstr = rest.read_cstr()?;
+                Ok(Cow::Owned(cstr.into_owned()))
+            }),
+        }
+    }
+
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        match self {
+            Cow::Borrowed(slice) => slice.read_until(delim),
+            Cow::Owned(vec) => consume_front(vec, |rest| {
+                let slice = rest.read_until(delim)?;
+                Ok(Cow::Owned(slice.into_owned()))
+            }),
+        }
+    }
+
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        Ok(mem::take(self).into_owned())
+    }
+
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        Ok(mem::take(self))
+    }
+}
+
+/// The `Peek<'data>` trait allows for looking at upcoming bytes of a
+/// [`Read<'data>`] without consuming them.
+///
+/// This is useful for format detection, such as checking magic bytes or
+/// dispatching on a tag, before committing to a particular parser.
+///
+/// Not every source can look ahead without buffering, so peeking is kept out of
+/// [`Read<'data>`]. The built-in readers that support peeking are:
+///
+/// * `&[u8]`, which returns bytes from the front of the slice.
+/// * [`Cursor<T>`], which returns bytes after its current position.
+/// * [`SliceReader<'data>`], which returns bytes after its current offset.
+/// * `VecDeque<u8>`, which returns bytes from the front of the queue.
+/// * [`IoReader<R>`], as long as `R` implements both [`io::BufRead`] and
+///   [`io::Seek`].
+///
+/// [`Read<'data>`]: Read
+/// [`SliceReader<'data>`]: SliceReader
+pub trait Peek<'data>: Read<'data> {
+    /// Reads exactly `N` bytes from this reader without consuming them.
+    ///
+    /// A subsequent read will return the same bytes again.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if fewer than `N`
+    /// bytes remain in this reader. In this case, no bytes are consumed.
+    ///
+    /// If any other error is encountered then this function immediately
+    /// returns, and it is unspecified how many bytes got consumed.
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]>;
+}
+
+impl<'data, P> Peek<'data> for &mut P
+where
+    P: ?Sized + Peek<'data>,
+{
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        (**self).peek_array()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data, P> Peek<'data> for Box<P>
+where
+    P: ?Sized + Peek<'data>,
+{
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        (**self).peek_array()
+    }
+}
+
+impl<'data> Peek<'data> for &'data [u8] {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut copy = *self;
+        copy.read_array()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl Peek<'_> for VecDeque<u8> {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.len() < N {
+            return Err(error!(UnexpectedEof, "failed to peek array"));
+        }
+
+        let mut array = [0; N];
+        for (dst, src) in array.iter_mut().zip(self.iter()) {
+            *dst = *src;
+        }
+        Ok(array)
+    }
+}
+
+/// The `ReadRef<'data>` trait is a [`Read<'data>`] that can hand out references
+/// into its underlying data.
+///
+/// Only readers backed by memory that lives for `'data` can implement this
+/// trait, which is why it is kept out of [`Read<'data>`]. The built-in readers
+/// that implement it are `&[u8]`, [`Cursor<&'data T>`], and
+/// [`SliceReader<'data>`].
+///
+/// [`Read<'data>`]: Read
+/// [`Cursor<&'data T>`]: Cursor
+/// [`SliceReader<'data>`]: SliceReader
+pub trait ReadRef<'data>: Read<'data> {
+    /// Reads exactly `N` bytes from this reader, returning a reference to them
+    /// instead of a copy.
+    ///
+    /// This is useful for large fixed-size fields, such as hashes, that don't
+    /// need to be copied out of the source.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if fewer than `N`
+    /// bytes remain in this reader. In this case, no bytes are consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::ReadRef;
+    ///
+    /// let data = [0xAB; 40];
+    /// let mut reader = &data[..];
+    ///
+    /// let hash: &[u8; 32] = reader.read_array_ref()?;
+    /// assert_eq!(hash, &[0xAB; 32]);
+    /// assert_eq!(reader.len(), 8);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]>;
+
+    /// Splits off the next `n` bytes of this reader as an independent
+    /// [`SliceReader<'data>`], and advances this reader past them.
+    ///
+    /// This is like [`take()`], except that the returned reader doesn't borrow
+    /// this one, so it can be handed to a recursive parse function while this
+    /// reader is used to continue after the frame. Every read from the
+    /// returned reader still borrows for `'data`.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if fewer than `n`
+    /// bytes remain in this reader. In this case, no bytes are consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadRef};
+    ///
+    /// let mut reader: &[u8] = b"\x03abc\x01d";
+    ///
+    /// let len = reader.read_next()?;
+    /// let mut frame = reader.read_sub(len.into())?;
+    /// assert_eq!(reader.read_next()?, 0x01);
+    ///
+    /// assert_eq!(frame.read_array()?, *b"ab");
+    /// assert_eq!(frame.remaining(), b"c");
+    /// assert!(reader.read_sub(2).is_err());
+    /// assert_eq!(reader, b"d");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`SliceReader<'data>`]: SliceReader
+    /// [`take()`]: ReadExt::take
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>>;
+
+    /// Splits off the next `n` bytes of this reader as a
+    /// [`ScopedReader<'data>`], and advances this reader past them.
+    ///
+    /// This is like [`read_sub()`], except that the returned reader checks
+    /// that the section is read entirely once it is finished.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_sub()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadRef};
+    ///
+    /// let mut reader: &[u8] = b"\x02ab!";
+    ///
+    /// let len = reader.read_next()?;
+    /// let mut chunk = reader.read_scoped(len.into())?;
+    /// assert_eq!(chunk.read_array()?, *b"ab");
+    /// chunk.finish()?;
+    /// assert_eq!(reader, b"!");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ScopedReader<'data>`]: ScopedReader
+    /// [`read_sub()`]: ReadRef::read_sub
+    #[inline]
+    fn read_scoped(&mut self, n: usize) -> Result<ScopedReader<'data>> {
+        self.read_sub(n).map(ScopedReader::new)
+    }
+}
+
+impl<'data, R> ReadRef<'data> for &mut R
+where
+    R: ?Sized + ReadRef<'data>,
+{
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        (**self).read_array_ref()
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        (**self).read_sub(n)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data, R> ReadRef<'data> for Box<R>
+where
+    R: ?Sized + ReadRef<'data>,
+{
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        (**self).read_array_ref()
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        (**self).read_sub(n)
+    }
+}
+
+impl<'data> ReadRef<'data> for &'data [u8] {
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        let Some((array, rest)) = self.split_first_chunk() else {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        };
+
+        *self = rest;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        let Some((head, rest)) = self.split_at_checked(n) else {
+            return Err(error!(UnexpectedEof, "failed to read sub-reader"));
+        };
+
+        *self = rest;
+        Ok(SliceReader::new(head))
+    }
+}
+
+/// The `BufRead<'data>` trait is a [`Read<'data>`] with an internal buffer
+/// that can be inspected directly.
+///
+/// Mirroring [`io::BufRead`], [`fill_buf()`] returns the bytes that are
+/// currently available without copying them, and [`consume()`] marks some of
+/// them as read. This makes borrowing reads possible even for sources that
+/// can't borrow for `'data`, such as [`IoReader<R>`] over an
+/// [`io::BufRead`], since the returned slice only lives as long as the
+/// borrow of the reader. The built-in readers that implement it are `&[u8]`,
+/// [`Cursor<T>`], [`SliceReader<'data>`], [`BufReader<R>`], and
+/// [`IoReader<R>`], as long as `R` implements [`io::BufRead`].
+///
+/// The borrow checker ensures that the slice returned by [`fill_buf()`] is no
+/// longer in use once [`consume()`] is called:
+///
+/// ```compile_fail
+/// use zc_io::BufRead;
+///
+/// let mut reader: &[u8] = b"abc";
+/// let buf = reader.fill_buf()?;
+/// reader.consume(1);
+/// assert_eq!(buf, b"abc");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+/// [`io::BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+/// [`fill_buf()`]: BufRead::fill_buf
+/// [`consume()`]: BufRead::consume
+/// [`IoReader<R>`]: IoReader
+/// [`Cursor<T>`]: Cursor
+/// [`SliceReader<'data>`]: SliceReader
+/// [`BufReader<R>`]: BufReader
+pub trait BufRead<'data>: Read<'data> {
+    /// Returns the contents of the internal buffer, filling it with more data
+    /// from the source if it is empty.
+    ///
+    /// An empty slice is returned only once the source has reached
+    /// end-of-file. The bytes are not consumed, so calling this method again
+    /// returns at least the same bytes until [`consume()`] is called.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`] then the error is ignored and the operation
+    /// will continue.
+    ///
+    /// If any other read error is encountered then this function immediately
+    /// returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BufRead, Read};
+    ///
+    /// let mut reader: &[u8] = b"key=value";
+    /// let buf = reader.fill_buf()?;
+    /// let len = buf.iter().position(|&byte| byte == b'=').unwrap_or(buf.len());
+    /// assert_eq!(&buf[..len], b"key");
+    ///
+    /// reader.consume(len + 1);
+    /// assert_eq!(reader.read_array()?, *b"value");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`consume()`]: BufRead::consume
+    fn fill_buf(&mut self) -> Result<&[u8]>;
+
+    /// Marks `amt` bytes of the internal buffer as read, so they are no longer
+    /// returned by [`fill_buf()`] or any other read.
+    ///
+    /// `amt` should not exceed the length of the slice most recently returned
+    /// by [`fill_buf()`]. If it does, the built-in readers consume at most
+    /// every buffered byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BufRead, Cursor, Read};
+    ///
+    /// let mut reader = Cursor::new(b"abc");
+    /// assert_eq!(reader.read_next()?, b'a');
+    ///
+    /// reader.consume(usize::MAX);
+    /// assert_eq!(reader.position(), 3);
+    /// assert!(reader.fill_buf()?.is_empty());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`fill_buf()`]: BufRead::fill_buf
+    fn consume(&mut self, amt: usize);
+
+    /// Skips past the next occurrence of `delim`, returning the number of
+    /// bytes discarded, including `delim` itself.
+    ///
+    /// Unlike [`read_until()`], the skipped bytes are never collected: each
+    /// buffer returned by [`fill_buf()`] is scanned and [consumed] in place,
+    /// so nothing is allocated, no matter how far away `delim` is.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if end-of-file is
+    /// reached before `delim` is found. In this case, every remaining byte is
+    /// consumed.
+    ///
+    /// If any other error is encountered then this function immediately
+    /// returns, and it is unspecified how many bytes got consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BufRead, Read};
+    ///
+    /// let mut reader: &[u8] = b"# comment\nkey=value";
+    /// assert_eq!(reader.discard_until(b'\n')?, 10);
+    /// assert_eq!(reader, b"key=value");
+    /// assert!(reader.discard_until(b'\n').unwrap_err().is_eof());
+    /// assert!(reader.is_empty());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_until()`]: Read::read_until
+    /// [`fill_buf()`]: BufRead::fill_buf
+    /// [consumed]: BufRead::consume
+    #[inline]
+    fn discard_until(&mut self, delim: u8) -> Result<usize> {
+        let mut discarded = 0;
+        loop {
+            let buf = self.fill_buf()?;
+            if buf.is_empty() {
+                return Err(error!(UnexpectedEof, "failed to find delimiter"));
+            }
+
+            let (len, found) = match buf.iter().position(|&byte| byte == delim) {
+                Some(index) => (index + 1, true),
+                None => (buf.len(), false),
+            };
+            self.consume(len);
+            discarded += len;
+            if found {
+                return Ok(discarded);
+            }
+        }
+    }
+}
+
+impl<'data, R> BufRead<'data> for &mut R
+where
+    R: ?Sized + BufRead<'data>,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        (**self).fill_buf()
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        (**self).consume(amt);
+    }
+
+    #[inline]
+    fn discard_until(&mut self, delim: u8) -> Result<usize> {
+        (**self).discard_until(delim)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data, R> BufRead<'data> for Box<R>
+where
+    R: ?Sized + BufRead<'data>,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        (**self).fill_buf()
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        (**self).consume(amt);
+    }
+
+    #[inline]
+    fn discard_until(&mut self, delim: u8) -> Result<usize> {
+        (**self).discard_until(delim)
+    }
+}
 
-        let (array, rest) = self.split_at(N);
-        *self = rest;
-        // SAFETY: a slice of bytes whose length is `N` is identical to
-        // `[u8; N]`.
-        Ok(unsafe { *array.as_ptr().cast::<[u8; N]>() })
+impl<'data> BufRead<'data> for &'data [u8] {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        *self = &self[cmp::min(amt, self.len())..];
     }
 }
 
@@ -187,13 +1727,75 @@ impl<'data> Read<'data> for &'data [u8] {
 /// zero-copy operations, meaning that [`read_slice`] will always return an
 /// [`Owned`] value.
 ///
+/// [`skip`] discards bytes by reading them through a fixed-size buffer, even
+/// if the underlying reader implements [`io::Seek`]. Seeking past the end of a
+/// stream is not an error, so seeking would make it impossible to report
+/// [`ErrorKind::UnexpectedEof`]. Callers that know their source is large
+/// enough may seek through [`get_mut`] instead.
+///
+/// To read many slices without allocating each of them, use
+/// [`read_slice_buffered`], which reuses an internal buffer.
+///
+/// Every read retries [`io::ErrorKind::Interrupted`] errors of the underlying
+/// reader, so they are never surfaced:
+///
+/// ```
+/// use std::io;
+/// use zc_io::{IoReader, Read};
+///
+/// struct Flaky(bool);
+///
+/// impl io::Read for Flaky {
+///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+///         self.0 = !self.0;
+///         if self.0 {
+///             return Err(io::ErrorKind::Interrupted.into());
+///         }
+///         buf.fill(0x2A);
+///         Ok(buf.len())
+///     }
+/// }
+///
+/// let mut reader = IoReader::new(Flaky(false));
+/// assert_eq!(reader.read_next()?, 0x2A);
+/// assert_eq!(reader.read_array()?, [0x2A; 4]);
+/// assert_eq!(reader.read_slice(3)?.as_ref(), [0x2A; 3]);
+/// reader.skip(2)?;
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// Reading zero bytes never touches the underlying reader:
+///
+/// ```
+/// use std::{borrow::Cow, io};
+/// use zc_io::{IoReader, Read};
+///
+/// struct Unreadable;
+///
+/// impl io::Read for Unreadable {
+///     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+///         unreachable!("the source must not be read");
+///     }
+/// }
+///
+/// let mut reader = IoReader::new(Unreadable);
+/// assert!(matches!(reader.read_slice(0)?, Cow::Borrowed([])));
+/// assert_eq!(reader.read_array::<0>()?, []);
+/// reader.read_exact_into(&mut [])?;
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
 /// [`Read<'data>`]: Read
 /// [`read_slice`]: Read::read_slice
 /// [`Owned`]: Cow::Owned
+/// [`skip`]: Read::skip
+/// [`get_mut`]: IoReader::get_mut
+/// [`read_slice_buffered`]: IoReader::read_slice_buffered
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 pub struct IoReader<R> {
     inner: R,
+    scratch: Vec<u8>,
 }
 
 #[cfg(feature = "std")]
@@ -202,10 +1804,160 @@ where
     R: io::Read,
 {
     /// Creates a new `IoReader<R>` from some reader.
+    ///
+    /// The scratch buffer starts out empty, and is only allocated once it is
+    /// needed.
     #[must_use]
     #[inline]
     pub fn new(reader: R) -> Self {
-        IoReader { inner: reader }
+        IoReader {
+            inner: reader,
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Creates a new `IoReader<R>` whose scratch buffer for
+    /// [`read_slice_buffered`] can hold at least `capacity` bytes without
+    /// reallocating.
+    ///
+    /// This only affects [`read_slice_buffered`] and [`copy_to`]. Other reads,
+    /// such as [`read_array`], which reads into an array on the stack, never
+    /// touch the scratch buffer.
+    ///
+    /// [`read_slice_buffered`]: IoReader::read_slice_buffered
+    /// [`copy_to`]: Read::copy_to
+    /// [`read_array`]: Read::read_array
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        IoReader {
+            inner: reader,
+            scratch: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes the scratch buffer can hold without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::IoReader;
+    ///
+    /// let mut reader = IoReader::with_capacity(16, &b"abc"[..]);
+    /// assert!(reader.capacity() >= 16);
+    ///
+    /// reader.read_slice_buffered(3)?;
+    /// assert!(reader.capacity() >= 16);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.scratch.capacity()
+    }
+
+    /// Reads exactly `len` bytes from this reader into an internal scratch
+    /// buffer, and returns a slice of it.
+    ///
+    /// Unlike [`read_slice`], which has to allocate a new [`Vec`] for every
+    /// call, the scratch buffer is reused, so repeated calls only allocate
+    /// when `len` exceeds every previous length. The returned slice borrows
+    /// this reader, so it must no longer be in use by the next read:
+    ///
+    /// ```compile_fail
+    /// use zc_io::IoReader;
+    ///
+    /// let mut reader = IoReader::new(&b"abcd"[..]);
+    /// let first = reader.read_slice_buffered(2)?;
+    /// let second = reader.read_slice_buffered(2)?;
+    /// assert_ne!(first, second);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_exact_into`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{IoReader, Read};
+    ///
+    /// let mut reader = IoReader::new(&b"\x05hello\x05world"[..]);
+    /// while let Ok(len) = reader.read_next() {
+    ///     let word = reader.read_slice_buffered(len.into())?;
+    ///     assert_eq!(word.len(), 5);
+    /// }
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice`]: Read::read_slice
+    /// [`read_exact_into`]: Read::read_exact_into
+    #[inline]
+    pub fn read_slice_buffered(&mut self, len: usize) -> Result<&[u8]> {
+        self.scratch.clear();
+        self.scratch.resize(len, 0);
+        self.inner.read_exact(&mut self.scratch)?;
+        Ok(&self.scratch)
+    }
+
+    /// Reads exactly `N` bytes from this reader into an array, reporting how
+    /// many were read if the underlying reader ends early.
+    ///
+    /// This behaves like [`read_array`], which relies on
+    /// [`io::Read::read_exact`], and thus can't tell how much of the array was
+    /// filled before end-of-file. Here, the array is filled by calling
+    /// [`io::Read::read`] repeatedly instead, so the error names the number of
+    /// bytes read, which helps to diagnose truncated records.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if the underlying
+    /// reader reaches end-of-file before `N` bytes were read, in which case
+    /// the bytes read are lost. Its message includes how many bytes were read.
+    ///
+    /// If the underlying reader returns an error other than
+    /// [`ErrorKind::Interrupted`], which is retried, it is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{ErrorKind, IoReader};
+    ///
+    /// let mut reader = IoReader::new(&b"abcdef"[..]);
+    /// assert_eq!(reader.read_array_reporting()?, *b"abcd");
+    ///
+    /// let error = reader.read_array_reporting::<4>().unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "failed to fill whole array, read 2 of 4 bytes",
+    /// );
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_array`]: Read::read_array
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    /// [`ErrorKind::Interrupted`]: crate::ErrorKind::Interrupted
+    #[inline]
+    pub fn read_array_reporting<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0; N];
+        let mut filled = 0;
+        while filled < N {
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) => {
+                    return Err(error!(
+                        UnexpectedEof,
+                        "failed to fill whole array, read {} of {} bytes", filled, N
+                    ));
+                }
+                Ok(n) => filled += n,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(buf)
     }
 
     /// Gets a reference to the underlying reader.
@@ -230,6 +1982,19 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<R> fmt::Debug for IoReader<R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IoReader")
+            .field("inner", &self.inner)
+            .field("capacity", &self.scratch.capacity())
+            .finish()
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<'data, R> Read<'data> for IoReader<R>
@@ -245,6 +2010,10 @@ where
 
     #[inline]
     fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
         let mut buf = vec![0; len];
         self.inner.read_exact(&mut buf)?;
         Ok(Cow::Owned(buf))
@@ -253,9 +2022,58 @@ where
     #[inline]
     fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
         let mut array = [0; N];
-        self.inner.read_exact(&mut array)?;
+        self.read_exact_into(&mut array)?;
         Ok(array)
     }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        self.inner.read_exact(buf)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        let n = n as u64;
+        if io::copy(&mut io::Read::take(&mut self.inner, n), &mut io::sink())? < n {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        io::Read::read_to_end(&mut self.inner, &mut buf)?;
+        Ok(buf)
+    }
+
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        let len = cmp::max(self.scratch.capacity(), IO_COPY_BUF_LEN);
+        self.scratch.clear();
+        self.scratch.resize(len, 0);
+
+        let mut copied = 0;
+        loop {
+            match self.inner.read(&mut self.scratch) {
+                Ok(0) => return Ok(copied),
+                Ok(n) => {
+                    writer.write_all(&self.scratch[..n])?;
+                    copied += n as u64;
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -339,9 +2157,90 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<R> Seek for IoReader<R>
+where
+    R: io::Seek,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let position = self.inner.seek(pos)?;
+        Ok(position)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<R> BufRead<'_> for IoReader<R>
+where
+    R: io::BufRead,
+{
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        loop {
+            match self.inner.fill_buf() {
+                Ok(_) => break,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(self.inner.fill_buf()?)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<R> Peek<'_> for IoReader<R>
+where
+    R: io::BufRead + io::Seek,
+{
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        if let Ok(buf) = self.inner.fill_buf() {
+            if buf.len() >= N {
+                array.copy_from_slice(&buf[..N]);
+                return Ok(array);
+            }
+        }
+
+        // The buffer doesn't hold enough bytes, so read past it and then seek
+        // back to where we started.
+        let mut filled = 0;
+        let result = loop {
+            if filled == N {
+                break Ok(());
+            }
+
+            match self.inner.read(&mut array[filled..]) {
+                Ok(0) => break Err(error!(UnexpectedEof, "failed to peek array")),
+                Ok(n) => filled += n,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => break Err(error.into()),
+            }
+        };
+
+        // `filled <= N`, and no array can be larger than `isize::MAX` bytes.
+        #[allow(clippy::cast_possible_wrap)]
+        self.inner.seek(SeekFrom::Current(-(filled as i64)))?;
+        result.map(|()| array)
+    }
+}
+
 /// A simplified facade of [`io::Write`] for easier use in possibly [`no_std`]
 /// environments.
 ///
+/// This trait only holds the methods a writer has to provide, along with the
+/// ones a writer may override to be more efficient. Conveniences derived from
+/// them, such as writing integers, are provided by the [`WriteExt`] extension
+/// trait instead, which is implemented for every `Write`.
+///
 /// [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 pub trait Write {
     /// Write a buffer into this writer, returning how many bytes were written.
@@ -404,15 +2303,178 @@ pub trait Write {
             match self.write(buf) {
                 Ok(0) => return Err(error!(WriteZero, "failed to write whole buffer")),
                 Ok(n) => buf = &buf[n..],
-                #[cfg(feature = "std")]
-                Err(ref error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(ref error) if error.is_interrupted() => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`write`], except that it writes from a slice of buffers.
+    ///
+    /// Data is copied from each buffer in order, with the final buffer read
+    /// from possibly being only partially consumed. This method must behave as
+    /// a call to [`write`] with the buffers concatenated would.
+    ///
+    /// The default implementation calls [`write`] with the first nonempty
+    /// buffer provided, or an empty one if none exists.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the same errors as [`write`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{IoSlice, Write};
+    ///
+    /// let mut buf = [0; 4];
+    /// let bufs = [IoSlice::new(b""), IoSlice::new(b"ab"), IoSlice::new(b"cd")];
+    ///
+    /// // `&mut [u8]` uses the default implementation.
+    /// assert_eq!((&mut buf[..]).write_vectored(&bufs)?, 2);
+    /// assert_eq!(buf, *b"ab\0\0");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write`]: Write::write
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        let buf = bufs
+            .iter()
+            .find(|buf| !buf.is_empty())
+            .map_or(&[][..], |buf| &**buf);
+        self.write(buf)
+    }
+
+    /// Attempts to write multiple buffers into this writer.
+    ///
+    /// This method will continuously call [`write_vectored`] until there is no
+    /// more data to be written or an error of non-[`ErrorKind::Interrupted`]
+    /// kind is returned. After every partial write, the buffers that were
+    /// written in full are skipped, and the buffer that was written in part is
+    /// trimmed to its unwritten bytes, so `bufs` is modified in the process.
+    ///
+    /// If the buffers contain no data, this will never call
+    /// [`write_vectored`].
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::WriteZero`] error is returned if [`write_vectored`]
+    /// returns `Ok(0)` before everything was written.
+    ///
+    /// Otherwise, this function will return the first error of
+    /// non-[`ErrorKind::Interrupted`] kind that [`write_vectored`] returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{IoSlice, Write};
+    ///
+    /// // A writer that accepts at most three bytes at a time.
+    /// struct Trickle(Vec<u8>);
+    ///
+    /// impl Write for Trickle {
+    ///     fn write(&mut self, buf: &[u8]) -> zc_io::Result<usize> {
+    ///         let n = buf.len().min(3);
+    ///         self.0.extend_from_slice(&buf[..n]);
+    ///         Ok(n)
+    ///     }
+    ///
+    ///     fn flush(&mut self) -> zc_io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut writer = Trickle(Vec::new());
+    /// let mut bufs = [
+    ///     IoSlice::new(b""),
+    ///     IoSlice::new(b"abcd"),
+    ///     IoSlice::new(b"e"),
+    ///     IoSlice::new(b"fghijkl"),
+    /// ];
+    /// writer.write_all_vectored(&mut bufs)?;
+    /// assert_eq!(writer.0, b"abcdefghijkl");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_vectored`]: Write::write_vectored
+    fn write_all_vectored(&mut self, mut bufs: &mut [IoSlice<'_>]) -> Result<()> {
+        // Skip any leading empty buffers, so that nothing is written when there
+        // is no data at all.
+        IoSlice::advance_slices(&mut bufs, 0);
+        while !bufs.is_empty() {
+            match self.write_vectored(bufs) {
+                Ok(0) => return Err(error!(WriteZero, "failed to write whole buffer")),
+                Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+                Err(ref error) if error.is_interrupted() => {}
                 Err(error) => return Err(error),
             }
         }
         Ok(())
     }
+
+    /// Writes a formatted string into this writer.
+    ///
+    /// This method is primarily used to interface with the [`format_args!()`]
+    /// macro, and it is rare that this should explicitly be called. The
+    /// [`write!()`] macro should be favored to invoke this method instead.
+    ///
+    /// The formatted string is written piece by piece with [`write_all()`]
+    /// through a [`FmtWriter`], without allocating, so this method works in
+    /// `no_std` environments.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the first error that [`write_all()`]
+    /// returns. If a formatting trait implementation fails on its own, an
+    /// error of the [`ErrorKind::Other`] kind is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::Write;
+    ///
+    /// let mut buf = [0; 9];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_fmt(format_args!("{} + {} = {}", 1, 2, 1 + 2))?;
+    /// assert_eq!(buf, *b"1 + 2 = 3");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`format_args!()`]: core::format_args
+    /// [`write!()`]: crate::write!
+    /// [`write_all()`]: Write::write_all
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<()> {
+        let mut output = FmtWriter::new(self);
+        let result = fmt::write(&mut output, fmt);
+        output.into_result()?;
+        result.map_err(|fmt::Error| error!(Other, "formatter error"))
+    }
 }
 
+/// Writers are forwarded through `&mut W`, so a writer can be passed on by
+/// mutable reference, at any depth. Since [`Write`] has no generic methods,
+/// this includes trait objects:
+///
+/// ```
+/// use zc_io::{Write, WriteExt};
+///
+/// fn header<W: Write>(mut writer: W) -> zc_io::Result<()> {
+///     writer.write_u16_be(0x0102)
+/// }
+///
+/// let mut buf = [0; 6];
+/// let mut writer = &mut buf[..];
+/// header(&mut writer)?;
+/// header(&mut &mut writer)?;
+///
+/// let object: &mut dyn Write = &mut writer;
+/// header(object)?;
+///
+/// assert_eq!(buf, [0x01, 0x02, 0x01, 0x02, 0x01, 0x02]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
 impl<W> Write for &mut W
 where
     W: ?Sized + Write,
@@ -431,8 +2493,49 @@ where
     fn write_all(&mut self, buf: &[u8]) -> Result<()> {
         (**self).write_all(buf)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        (**self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> Result<()> {
+        (**self).write_all_vectored(bufs)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<()> {
+        (**self).write_fmt(fmt)
+    }
 }
 
+/// Writers are forwarded through [`Box<W>`] as well, so any nesting of boxes
+/// and mutable references is a writer too, including boxed trait objects:
+///
+/// ```
+/// use zc_io::{Write, WriteExt};
+///
+/// fn header<W: Write>(mut writer: W) -> zc_io::Result<()> {
+///     writer.write_u16_be(0x0102)
+/// }
+///
+/// let mut buf = Vec::new();
+/// header(&mut buf)?;
+/// header(&mut Box::new(&mut buf))?;
+///
+/// let mut object: Box<dyn Write> = Box::new(&mut buf);
+/// header(&mut object)?;
+/// header(&mut *object)?;
+/// drop(object);
+///
+/// assert_eq!(buf, [0x01, 0x02].repeat(4));
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Box<W>`]: Box
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 impl<W> Write for Box<W>
 where
     W: ?Sized + Write,
@@ -451,6 +2554,21 @@ where
     fn write_all(&mut self, buf: &[u8]) -> Result<()> {
         (**self).write_all(buf)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        (**self).write_vectored(bufs)
+    }
+
+    #[inline]
+    fn write_all_vectored(&mut self, bufs: &mut [IoSlice<'_>]) -> Result<()> {
+        (**self).write_all_vectored(bufs)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<()> {
+        (**self).write_fmt(fmt)
+    }
 }
 
 /// Write is implemented for `&mut [u8]` by copying into the slice, overwriting
@@ -489,6 +2607,8 @@ impl Write for &mut [u8] {
 
 /// Write is implemented for `Vec<u8>` by appending to the vector. The vector
 /// will grow as needed.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 impl Write for Vec<u8> {
     #[inline]
     fn write(&mut self, data: &[u8]) -> Result<usize> {
@@ -506,11 +2626,57 @@ impl Write for Vec<u8> {
         self.extend_from_slice(data);
         Ok(())
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        let len = bufs.iter().map(|buf| buf.len()).sum();
+        self.reserve(len);
+        for buf in bufs {
+            self.extend_from_slice(buf);
+        }
+        Ok(len)
+    }
+}
+
+/// Write is implemented for `VecDeque<u8>` by appending to the back of the
+/// queue. The queue will grow as needed.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use zc_io::Write;
+///
+/// let mut queue = VecDeque::from(vec![1, 2]);
+/// queue.write_all(&[3, 4])?;
+/// assert_eq!(queue, [1, 2, 3, 4]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl Write for VecDeque<u8> {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> Result<usize> {
+        self.extend(data);
+        Ok(data.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_all(&mut self, data: &[u8]) -> Result<()> {
+        self.extend(data);
+        Ok(())
+    }
 }
 
 /// The `IoWriter<W>` struct implements [`Write`] to any I/O writer.
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[derive(Debug)]
 pub struct IoWriter<W> {
     inner: W,
 }
@@ -572,6 +2738,18 @@ where
         self.inner.write_all(buf)?;
         Ok(())
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        let amount = self.inner.write_vectored(bufs)?;
+        Ok(amount)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<()> {
+        self.inner.write_fmt(fmt)?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -600,3 +2778,41 @@ where
         self.inner.write_fmt(fmt)
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<W> Seek for IoWriter<W>
+where
+    W: io::Seek,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let position = self.inner.seek(pos)?;
+        Ok(position)
+    }
+}
+
+#[cfg(feature = "io-impls")]
+impl_read_from_io!(
+    std::fs::File,
+    &std::fs::File,
+    std::net::TcpStream,
+    &std::net::TcpStream,
+    io::Stdin,
+    io::StdinLock<'_>,
+    std::process::ChildStdout,
+    std::process::ChildStderr,
+);
+
+#[cfg(feature = "io-impls")]
+impl_write_from_io!(
+    std::fs::File,
+    &std::fs::File,
+    std::net::TcpStream,
+    &std::net::TcpStream,
+    io::Stdout,
+    io::StdoutLock<'_>,
+    io::Stderr,
+    io::StderrLock<'_>,
+    std::process::ChildStdin,
+);
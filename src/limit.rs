@@ -0,0 +1,113 @@
+use crate::{Result, Write};
+
+use core::cmp;
+
+/// A writer adapter which limits the bytes written to an underlying writer.
+///
+/// Reaching the limit is not an error in itself. A [`write`] that would cross
+/// the limit is clamped, and returns a short count of the bytes that fit. Once
+/// the limit is exhausted, every [`write`] returns `Ok(0)`, which
+/// [`write_all`] reports as an [`ErrorKind::WriteZero`] error, after writing
+/// as much of its buffer as the limit allowed.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{LimitWriter, Write};
+///
+/// let mut buf = [0; 8];
+/// let mut writer = LimitWriter::new(&mut buf[..], 5);
+///
+/// assert_eq!(writer.write(b"abc")?, 3);
+/// assert!(writer.write_all(b"defg").is_err());
+/// assert_eq!(writer.remaining(), 0);
+/// assert_eq!(writer.write(b"h")?, 0);
+///
+/// assert_eq!(writer.into_inner().len(), 3);
+/// assert_eq!(buf, *b"abcde\0\0\0");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`write`]: Write::write
+/// [`write_all`]: Write::write_all
+/// [`ErrorKind::WriteZero`]: crate::ErrorKind::WriteZero
+#[derive(Debug)]
+pub struct LimitWriter<W> {
+    inner: W,
+    limit: u64,
+}
+
+impl<W> LimitWriter<W> {
+    /// Creates a new `LimitWriter<W>` which will write at most `limit` bytes
+    /// to `inner`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: W, limit: u64) -> Self {
+        LimitWriter { inner, limit }
+    }
+
+    /// Returns the number of bytes that can be written before this instance
+    /// will stop accepting bytes.
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the number of bytes that can be written before this instance will
+    /// stop accepting bytes. This is the same as constructing a new
+    /// `LimitWriter<W>` instance, so the amount of bytes written and the
+    /// previous limit value don't matter when calling this method.
+    #[inline]
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Bytes written directly to the underlying writer don't count towards
+    /// the limit.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the `LimitWriter<W>`, returning the underlying writer.
+    ///
+    /// Note that any leftover limit is lost.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Write for LimitWriter<W>
+where
+    W: Write,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = usize::try_from(self.limit).map_or(buf.len(), |limit| cmp::min(buf.len(), limit));
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let n = self.inner.write(&buf[..len])?;
+        self.limit -= n as u64;
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,240 @@
+/// Writes formatted data into a [`zc_io::Write`].
+///
+/// This macro accepts a writer and a format string, followed by the arguments
+/// of the format string, just like [`std::write!`]. It expands to a call to
+/// [`Write::write_fmt`], and returns whatever it returns, which is a
+/// [`zc_io::Result<()>`].
+///
+/// Being exported by [`zc_io`], this macro shadows nothing: unless it is
+/// imported explicitly, the standard [`write!`] keeps working as usual, so
+/// invoke it as `zc_io::write!` or import it with `use zc_io::write;`.
+///
+/// # Examples
+///
+/// The formatting doesn't allocate, so it works in `no_std` environments as
+/// well:
+///
+/// ```
+/// let mut buf = [0; 5];
+/// let mut writer = &mut buf[..];
+/// zc_io::write!(writer, "{:02x}", 0xab)?;
+/// zc_io::write!(&mut writer, "-{}", "cd")?;
+/// assert_eq!(buf, *b"ab-cd");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`zc_io::Write`]: crate::Write
+/// [`std::write!`]: https://doc.rust-lang.org/std/macro.write.html
+/// [`Write::write_fmt`]: crate::Write::write_fmt
+/// [`zc_io::Result<()>`]: crate::Result
+/// [`zc_io`]: crate
+/// [`write!`]: core::write
+#[macro_export]
+macro_rules! write {
+    ($dst:expr, $($arg:tt)*) => {{
+        use $crate::Write as _;
+        $dst.write_fmt(::core::format_args!($($arg)*))
+    }};
+}
+
+/// Writes formatted data into a [`zc_io::Write`], with a newline appended.
+///
+/// This is identical to [`zc_io::write!`], except that a newline (`\n`) is
+/// written after the formatted data. With only a writer, just the newline is
+/// written.
+///
+/// # Examples
+///
+/// ```
+/// let mut buf = [0; 7];
+/// let mut writer = &mut buf[..];
+/// zc_io::writeln!(writer, "a = {}", 1)?;
+/// zc_io::writeln!(writer)?;
+/// assert_eq!(buf, *b"a = 1\n\n");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`zc_io::Write`]: crate::Write
+/// [`zc_io::write!`]: crate::write!
+#[macro_export]
+macro_rules! writeln {
+    ($dst:expr $(,)?) => {
+        $crate::write!($dst, "\n")
+    };
+    ($dst:expr, $($arg:tt)*) => {
+        $crate::write!($dst, "{}\n", ::core::format_args!($($arg)*))
+    };
+}
+
+/// Implements [`zc_io::Read`] for types that implement [`io::Read`].
+///
+/// Wrapping a reader in an [`IoReader<R>`] is the general way to read from it,
+/// but for types that are always read through [`zc_io::Read`], this macro
+/// removes the wrapper. It implements [`zc_io::Read<'data>`] for every listed
+/// type, forwarding each method to a temporary [`IoReader<R>`] over a mutable
+/// reference, so the implementations behave exactly like an
+/// [`IoReader<R>`] would, except that there is no scratch buffer for
+/// [`read_slice_buffered`].
+///
+/// A blanket implementation for every [`io::Read`] is deliberately not
+/// provided: it would conflict with the implementations for `&[u8]`,
+/// `VecDeque<u8>`, and [`Cursor<T>`], which are zero-copy or otherwise more
+/// efficient, and prevent downstream crates from implementing
+/// [`zc_io::Read`] for their own [`io::Read`] types. The `io-impls` feature
+/// applies this macro to the readers of the standard library instead, such as
+/// [`File`] and [`TcpStream`].
+///
+/// Like any trait implementation, the coherence rules only allow this macro
+/// to be used for types of the invoking crate.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use zc_io::Read;
+///
+/// struct Fill(u8);
+///
+/// impl io::Read for Fill {
+///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+///         buf.fill(self.0);
+///         Ok(buf.len())
+///     }
+/// }
+///
+/// zc_io::impl_read_from_io!(Fill);
+///
+/// let mut source = Fill(0x2A);
+/// assert_eq!(source.read_array()?, [0x2A; 3]);
+/// assert_eq!(source.read_slice(2)?.as_ref(), [0x2A; 2]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`zc_io::Read`]: crate::Read
+/// [`io::Read`]: std::io::Read
+/// [`IoReader<R>`]: crate::IoReader
+/// [`zc_io::Read<'data>`]: crate::Read
+/// [`read_slice_buffered`]: crate::IoReader::read_slice_buffered
+/// [`Cursor<T>`]: crate::Cursor
+/// [`File`]: std::fs::File
+/// [`TcpStream`]: std::net::TcpStream
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! impl_read_from_io {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl<'data> $crate::Read<'data> for $ty {
+            #[inline]
+            fn read_next(&mut self) -> $crate::Result<u8> {
+                $crate::Read::read_next(&mut $crate::IoReader::new(self))
+            }
+
+            #[inline]
+            fn read_slice(
+                &mut self,
+                len: usize,
+            ) -> $crate::Result<::std::borrow::Cow<'data, [u8]>> {
+                $crate::Read::read_slice(&mut $crate::IoReader::new(self), len)
+            }
+
+            #[inline]
+            fn read_array<const N: usize>(&mut self) -> $crate::Result<[u8; N]> {
+                $crate::Read::read_array(&mut $crate::IoReader::new(self))
+            }
+
+            #[inline]
+            fn read_exact_into(&mut self, buf: &mut [u8]) -> $crate::Result<()> {
+                $crate::Read::read_exact_into(&mut $crate::IoReader::new(self), buf)
+            }
+
+            #[inline]
+            fn skip(&mut self, n: usize) -> $crate::Result<()> {
+                $crate::Read::skip(&mut $crate::IoReader::new(self), n)
+            }
+
+            #[inline]
+            fn read_to_end(&mut self) -> $crate::Result<::std::vec::Vec<u8>> {
+                $crate::Read::read_to_end(&mut $crate::IoReader::new(self))
+            }
+        }
+    )+};
+}
+
+/// Implements [`zc_io::Write`] for types that implement [`io::Write`].
+///
+/// This is the counterpart of [`impl_read_from_io!`] for writers: every method
+/// of [`zc_io::Write`] forwards to a temporary [`IoWriter<W>`] over a mutable
+/// reference. A blanket implementation is not provided for the same reasons,
+/// and the `io-impls` feature applies this macro to the writers of the
+/// standard library.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use zc_io::WriteExt;
+///
+/// struct Log(Vec<u8>);
+///
+/// impl io::Write for Log {
+///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+///         self.0.extend_from_slice(buf);
+///         Ok(buf.len())
+///     }
+///
+///     fn flush(&mut self) -> io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// zc_io::impl_write_from_io!(Log);
+///
+/// let mut log = Log(Vec::new());
+/// log.write_u16_be(0x0102)?;
+/// assert_eq!(log.0, [0x01, 0x02]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`zc_io::Write`]: crate::Write
+/// [`io::Write`]: std::io::Write
+/// [`impl_read_from_io!`]: crate::impl_read_from_io!
+/// [`IoWriter<W>`]: crate::IoWriter
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! impl_write_from_io {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl $crate::Write for $ty {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> $crate::Result<usize> {
+                $crate::Write::write(&mut $crate::IoWriter::new(self), buf)
+            }
+
+            #[inline]
+            fn flush(&mut self) -> $crate::Result<()> {
+                $crate::Write::flush(&mut $crate::IoWriter::new(self))
+            }
+
+            #[inline]
+            fn write_all(&mut self, buf: &[u8]) -> $crate::Result<()> {
+                $crate::Write::write_all(&mut $crate::IoWriter::new(self), buf)
+            }
+
+            #[inline]
+            fn write_vectored(
+                &mut self,
+                bufs: &[$crate::IoSlice<'_>],
+            ) -> $crate::Result<usize> {
+                $crate::Write::write_vectored(&mut $crate::IoWriter::new(self), bufs)
+            }
+
+            #[inline]
+            fn write_fmt(
+                &mut self,
+                fmt: ::core::fmt::Arguments<'_>,
+            ) -> $crate::Result<()> {
+                $crate::Write::write_fmt(&mut $crate::IoWriter::new(self), fmt)
+            }
+        }
+    )+};
+}
@@ -0,0 +1,135 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::fmt;
+
+/// A reader adapter which transforms every byte read from an underlying
+/// reader with a closure.
+///
+/// This is useful for byte-level transforms, such as case folding or simple
+/// ciphers. The closure is called exactly once per byte, in order, including
+/// for bytes that are skipped, so it may keep state between calls.
+///
+/// Any transform inherently forfeits zero-copy reads, since the transformed
+/// bytes don't exist in the source. Even over an in-memory reader,
+/// [`read_slice`] will always return an [`Owned`] value.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{MapReader, Read};
+///
+/// let mut reader = MapReader::new(&b"Hello"[..], |byte: u8| byte.to_ascii_uppercase());
+/// assert_eq!(reader.read_next()?, b'H');
+/// assert_eq!(reader.read_array()?, *b"EL");
+/// assert_eq!(reader.read_array()?, *b"LO");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+pub struct MapReader<R, F> {
+    inner: R,
+    f: F,
+}
+
+impl<R, F> MapReader<R, F> {
+    /// Creates a new `MapReader<R, F>` which transforms the bytes of `inner`
+    /// with `f`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: R, f: F) -> Self {
+        MapReader { inner, f }
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader are not transformed.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `MapReader<R, F>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R, F> MapReader<R, F>
+where
+    F: FnMut(u8) -> u8,
+{
+    /// Transforms every byte of `buf` in place.
+    #[inline]
+    fn apply(&mut self, buf: &mut [u8]) {
+        for byte in buf {
+            *byte = (self.f)(*byte);
+        }
+    }
+}
+
+impl<R, F> fmt::Debug for MapReader<R, F>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapReader")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'data, R, F> Read<'data> for MapReader<R, F>
+where
+    R: Read<'data>,
+    F: FnMut(u8) -> u8,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        let byte = self.inner.read_next()?;
+        Ok((self.f)(byte))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = self.inner.read_slice(len)?.into_owned();
+        self.apply(&mut buf);
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = self.inner.read_array()?;
+        self.apply(&mut array);
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact_into(buf)?;
+        self.apply(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        self.inner.remaining_hint()
+    }
+}
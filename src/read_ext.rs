@@ -0,0 +1,1244 @@
+#[cfg(feature = "alloc")]
+use crate::Error;
+use crate::{
+    leb128, ByteOrder, Bytes, Chain, Float, Peek, Primitive, Read, Records, Result, Take, Wrapped,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+#[cfg(feature = "alloc")]
+use core::{cmp, mem, str};
+
+/// Validates possibly borrowed bytes as UTF-8, reusing the allocation of an
+/// [`Owned`] value.
+///
+/// [`Owned`]: Cow::Owned
+#[cfg(feature = "alloc")]
+fn cow_to_str(bytes: Cow<'_, [u8]>) -> Result<Cow<'_, str>> {
+    match bytes {
+        Cow::Borrowed(bytes) => match str::from_utf8(bytes) {
+            Ok(string) => Ok(Cow::Borrowed(string)),
+            Err(error) => Err(Error::invalid_utf8(error)),
+        },
+        Cow::Owned(bytes) => match String::from_utf8(bytes) {
+            Ok(string) => Ok(Cow::Owned(string)),
+            Err(error) => Err(Error::invalid_utf8(error.utf8_error())),
+        },
+    }
+}
+
+/// Copies `bytes` into a newly allocated, and thus correctly aligned, vector
+/// of `count` values.
+#[cfg(feature = "bytemuck")]
+fn copy_aligned<T>(bytes: &[u8], count: usize) -> Vec<T>
+where
+    T: Pod,
+{
+    let mut values = vec![T::zeroed(); count];
+    bytemuck::cast_slice_mut::<T, u8>(&mut values).copy_from_slice(bytes);
+    values
+}
+
+/// The most memory reserved upfront for elements whose count was read from a
+/// source, which can't be trusted.
+#[cfg(feature = "alloc")]
+const MAX_PREALLOC_BYTES: usize = 64 * 1024;
+
+/// Converts a length read from a source into a `usize`, failing instead of
+/// truncating it on targets where it doesn't fit.
+#[cfg(feature = "alloc")]
+fn addressable(len: u64) -> Result<usize> {
+    usize::try_from(len).map_err(|_| error!(InvalidData, "length exceeds addressable memory"))
+}
+
+/// Reads the `len` bytes that follow a length prefix, failing before anything
+/// is allocated if `reader` is known to hold fewer.
+#[cfg(feature = "alloc")]
+fn read_prefixed<'data, R>(reader: &mut R, len: u64) -> Result<Cow<'data, [u8]>>
+where
+    R: ?Sized + Read<'data>,
+{
+    let len = addressable(len)?;
+    if reader
+        .remaining_hint()
+        .is_some_and(|remaining| len > remaining)
+    {
+        return Err(error!(UnexpectedEof, "failed to read slice"));
+    }
+    reader.read_slice(len)
+}
+
+macro_rules! read_prefixed_fns {
+    ($($name:ident -> $read:ident, $length:literal;)*) => {$(
+        #[doc = concat!("Reads a ", $length, " length, followed by that many bytes, borrowing them if possible.")]
+        ///
+        /// As the length comes from the source, a corrupt or malicious one
+        /// may ask for far more bytes than exist. If the [`remaining_hint()`]
+        /// of this reader is smaller than the length, this fails before
+        /// anything is allocated. Otherwise, wrap readers of untrusted input in
+        /// a [`GuardedReader<R>`] to reject such lengths.
+        ///
+        /// # Errors
+        ///
+        /// An [`ErrorKind::InvalidData`] error is returned if the length
+        /// exceeds the addressable memory of the target, and an
+        /// [`ErrorKind::UnexpectedEof`] error if it exceeds the
+        /// [`remaining_hint()`] of this reader.
+        ///
+        /// Otherwise, this method returns the same errors as [`read_array()`]
+        /// and [`read_slice()`].
+        ///
+        /// [`remaining_hint()`]: Read::remaining_hint
+        /// [`GuardedReader<R>`]: crate::GuardedReader
+        /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+        /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+        /// [`read_array()`]: Read::read_array
+        /// [`read_slice()`]: Read::read_slice
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        fn $name(&mut self) -> Result<Cow<'data, [u8]>> {
+            let len = self.$read()?;
+            read_prefixed(self, len.into())
+        }
+    )*};
+}
+
+macro_rules! read_int_fns {
+    ($($name:ident -> $ty:ident, $from:ident, $endian:literal;)*) => {$(
+        #[doc = concat!("Reads a ", $endian, "-endian [`", stringify!($ty), "`] from this reader.")]
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`read_array()`].
+        ///
+        /// [`read_array()`]: Read::read_array
+        #[inline]
+        fn $name(&mut self) -> Result<$ty> {
+            Ok($ty::$from(self.read_array()?))
+        }
+    )*};
+}
+
+/// The `ReadExt<'data>` trait extends every [`Read<'data>`] with convenience
+/// methods.
+///
+/// [`Read<'data>`] only holds the methods that a reader has to provide, and
+/// the ones a reader may want to override for efficiency, such as
+/// [`read_exact_into()`] or [`skip()`]. Everything that is derived from them,
+/// like reading integers, strings, and LEB128 values, or wrapping a reader in
+/// an adapter, lives here instead, so that implementors of [`Read<'data>`]
+/// never have to care about it, and adding new conveniences never affects
+/// them.
+///
+/// This trait is implemented for every [`Read<'data>`] and cannot be
+/// implemented otherwise. To use its methods, import it alongside
+/// [`Read<'data>`]:
+///
+/// ```
+/// use zc_io::{Read, ReadExt};
+///
+/// let mut reader: &[u8] = &[0x01, 0x02, 0x2A];
+/// assert_eq!(reader.read_u16_be()?, 0x0102);
+/// assert_eq!(reader.read_next()?, 0x2A);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+/// [`read_exact_into()`]: Read::read_exact_into
+/// [`skip()`]: Read::skip
+pub trait ReadExt<'data>: Read<'data> {
+    /// Creates a "by reference" adapter for this instance of `Read<'data>`.
+    ///
+    /// The returned adapter also implements `Read<'data>` and will simply
+    /// borrow this current reader, so adapters that take a reader by value,
+    /// such as [`take()`], can be used without giving up ownership.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"abcdef";
+    /// assert_eq!(reader.by_ref().take(2).read_array()?, *b"ab");
+    /// assert_eq!(reader, b"cdef");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`take()`]: ReadExt::take
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Creates an adapter which will read at most `limit` bytes from this
+    /// reader.
+    ///
+    /// This is useful for handing a length-prefixed section of a stream to a
+    /// nested parser without letting it read past the end of that section.
+    /// Once `limit` bytes have been read, the returned [`Take<Self>`] reports
+    /// end-of-file, and requests that would cross the limit fail, except for
+    /// [`read_slice()`], which is clamped to the remaining limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"\x02abcd";
+    /// let len = reader.read_next()?;
+    ///
+    /// let mut section = reader.by_ref().take(len.into());
+    /// assert_eq!(section.read_array()?, *b"ab");
+    /// assert!(section.read_next().is_err());
+    ///
+    /// assert_eq!(reader, b"cd");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`Take<Self>`]: Take
+    /// [`read_slice()`]: Read::read_slice
+    #[inline]
+    fn take(self, limit: u64) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, limit)
+    }
+
+    /// Creates an adapter which will read from this reader until it is
+    /// exhausted, and then from `next`.
+    ///
+    /// A borrowed slice cannot span two sources, so whenever a read crosses
+    /// from this reader into `next`, the returned [`Chain<Self, U>`] copies the
+    /// bytes into an [`Owned`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let header: &[u8] = b"head";
+    /// let body: &[u8] = b"body";
+    /// let mut reader = header.chain(body);
+    ///
+    /// assert_eq!(reader.read_array()?, *b"he");
+    /// assert_eq!(reader.read_array()?, *b"adbo");
+    /// assert_eq!(reader.read_array()?, *b"dy");
+    /// assert!(reader.read_next().is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// With `alloc`, [`read_slice()`] borrows whenever the bytes come from a
+    /// single source:
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use std::borrow::Cow;
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let header: &[u8] = b"head";
+    /// let body: &[u8] = b"body";
+    /// let mut reader = header.chain(body);
+    ///
+    /// assert!(matches!(reader.read_slice(2)?, Cow::Borrowed(b"he")));
+    /// assert!(matches!(reader.read_slice(4)?, Cow::Owned(bytes) if bytes == b"adbo"));
+    /// assert!(matches!(reader.read_slice(2)?, Cow::Borrowed(b"dy")));
+    ///
+    /// let mut reader = header.chain(body);
+    /// assert!(matches!(reader.read_slice(4)?, Cow::Borrowed(b"head")));
+    /// assert!(matches!(reader.read_slice(4)?, Cow::Borrowed(b"body")));
+    /// # }
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`Chain<Self, U>`]: Chain
+    /// [`Owned`]: Cow::Owned
+    /// [`read_slice()`]: Read::read_slice
+    #[inline]
+    fn chain<U>(self, next: U) -> Chain<Self, U>
+    where
+        Self: Sized,
+        U: Read<'data>,
+    {
+        Chain::new(self, next)
+    }
+
+    /// Transforms this reader into an [`Iterator`] over its bytes.
+    ///
+    /// The returned [`Bytes<Self>`] yields each byte read with [`read_next()`]
+    /// and stops when this reader reaches end-of-file. Any other error is
+    /// yielded exactly once, after which iteration stops as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let reader: &[u8] = &[1, 2, 3];
+    /// let sum = reader.bytes().try_fold(0, |sum, byte| byte.map(|byte| sum + byte))?;
+    /// assert_eq!(sum, 6);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`Bytes<Self>`]: Bytes
+    /// [`read_next()`]: Read::read_next
+    #[inline]
+    fn bytes(self) -> Bytes<Self>
+    where
+        Self: Sized,
+    {
+        Bytes::new(self)
+    }
+
+    /// Transforms this reader into an [`Iterator`] over records parsed by `f`.
+    ///
+    /// The returned [`Records<Self, F>`] calls `f` once per record, and stops
+    /// when this reader is at end-of-file between two records. To detect this,
+    /// it peeks at one byte with [`peek_array()`] before every call to `f`,
+    /// which is why this reader has to implement [`Peek<'data>`]. If the
+    /// stream ends in the middle of a record instead, `f` fails to read it, and
+    /// its error is yielded.
+    ///
+    /// An error, whether from `f` or from peeking, is yielded exactly once,
+    /// after which iteration stops, since the reader no longer is at a record
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt, ReadRef};
+    ///
+    /// let reader: &[u8] = b"\x01a\x02bc";
+    /// let records = reader
+    ///     .records(|reader| {
+    ///         let len = reader.read_next()?;
+    ///         Ok(reader.read_sub(len.into())?.remaining())
+    ///     })
+    ///     .collect::<zc_io::Result<Vec<_>>>()?;
+    /// assert_eq!(records, [&b"a"[..], &b"bc"[..]]);
+    ///
+    /// let reader: &[u8] = b"abc";
+    /// let mut records = reader.records(|reader| reader.read_array::<2>());
+    /// assert_eq!(records.next().transpose()?, Some(*b"ab"));
+    /// assert!(records.next().unwrap().unwrap_err().is_eof());
+    /// assert!(records.next().is_none());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`Records<Self, F>`]: Records
+    /// [`peek_array()`]: Peek::peek_array
+    /// [`Peek<'data>`]: Peek
+    #[inline]
+    fn records<T, F>(self, f: F) -> Records<Self, F>
+    where
+        Self: Sized + Peek<'data>,
+        F: FnMut(&mut Self) -> Result<T>,
+    {
+        Records::new(self, f)
+    }
+
+    /// Reads the next byte from this reader, retrying for as long as it fails
+    /// with an [`ErrorKind::Interrupted`] error.
+    ///
+    /// The readers of this crate never surface interruptions, but a custom
+    /// [`Read<'data>`] may. A single byte is either read or not, so it is
+    /// always safe to retry [`read_next()`]. Multi-byte reads may have consumed
+    /// part of their input before failing, which is why there is no retrying
+    /// counterpart for them; such readers should retry internally instead.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_next()`], except for
+    /// [`ErrorKind::Interrupted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Error, ErrorKind, Read, ReadExt, Result};
+    ///
+    /// struct Flaky<'a>(&'a [u8], bool);
+    ///
+    /// impl<'data> Read<'data> for Flaky<'data> {
+    ///     fn read_next(&mut self) -> Result<u8> {
+    ///         self.1 = !self.1;
+    ///         if self.1 {
+    ///             return Err(Error::from(ErrorKind::Interrupted));
+    ///         }
+    ///         self.0.read_next()
+    ///     }
+    ///
+    ///     # #[cfg(feature = "alloc")]
+    ///     # fn read_slice(&mut self, n: usize) -> Result<std::borrow::Cow<'data, [u8]>> {
+    ///     #     self.0.read_slice(n)
+    ///     # }
+    ///     #
+    ///     fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+    ///         self.0.read_array()
+    ///     }
+    /// }
+    ///
+    /// let mut reader = Flaky(b"ab", false);
+    /// assert_eq!(reader.read_next_retry()?, b'a');
+    /// assert_eq!(reader.read_next_retry()?, b'b');
+    /// assert!(reader.read_next_retry().unwrap_err().is_eof());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::Interrupted`]: crate::ErrorKind::Interrupted
+    /// [`Read<'data>`]: Read
+    /// [`read_next()`]: Read::read_next
+    #[inline]
+    fn read_next_retry(&mut self) -> Result<u8> {
+        loop {
+            match self.read_next() {
+                Err(error) if error.is_interrupted() => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Reads exactly `N` bytes from this reader, or returns `None` if it is at
+    /// end-of-file.
+    ///
+    /// This tells a clean end of a stream of records apart from a truncated
+    /// record. The first byte is read with [`read_next()`], and only if that
+    /// succeeds are the other `N - 1` bytes read with [`read_exact_into()`].
+    /// For an [`IoReader<R>`], this costs one extra read of the underlying
+    /// reader per record.
+    ///
+    /// If `N` is `0`, nothing is read and an empty array is always returned.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::UnexpectedEof`] error is returned if end-of-file is
+    /// reached after the first byte, but before all `N` bytes were read.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`] and
+    /// [`read_exact_into()`], except that an end-of-file error of the former
+    /// results in `None` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::ReadExt;
+    ///
+    /// let mut reader: &[u8] = b"abcd";
+    /// assert_eq!(reader.read_array_or_eof()?, Some(*b"ab"));
+    /// assert_eq!(reader.read_array_or_eof()?, Some(*b"cd"));
+    /// assert_eq!(reader.read_array_or_eof::<2>()?, None);
+    ///
+    /// let mut reader: &[u8] = b"abc";
+    /// assert_eq!(reader.read_array_or_eof()?, Some(*b"ab"));
+    /// assert!(reader.read_array_or_eof::<2>().unwrap_err().is_eof());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_next()`]: Read::read_next
+    /// [`read_exact_into()`]: Read::read_exact_into
+    /// [`IoReader<R>`]: crate::IoReader
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[inline]
+    fn read_array_or_eof<const N: usize>(&mut self) -> Result<Option<[u8; N]>> {
+        let mut array = [0; N];
+        let Some((first, rest)) = array.split_first_mut() else {
+            return Ok(Some(array));
+        };
+
+        match self.read_next() {
+            Ok(byte) => *first = byte,
+            Err(error) if error.is_eof() => return Ok(None),
+            Err(error) => return Err(error),
+        }
+        self.read_exact_into(rest)?;
+        Ok(Some(array))
+    }
+
+    /// Reads exactly `n` bytes from this reader, borrowing bytes if possible.
+    ///
+    /// This calls [`read_slice()`] and checks the length of what it returned,
+    /// so callers can rely on getting exactly `n` bytes. Every reader of this
+    /// crate already guarantees that, which makes the check a cheap safety net
+    /// against a misbehaving implementation of [`Read<'data>`].
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if [`read_slice()`]
+    /// returned more or fewer than `n` bytes.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_slice()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::ReadExt;
+    ///
+    /// let mut reader: &[u8] = b"abcdef";
+    /// assert_eq!(reader.read_slice_exact(4)?.as_ref(), b"abcd");
+    /// assert!(reader.read_slice_exact(4).is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`Read<'data>`]: Read
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_slice_exact(&mut self, n: usize) -> Result<Cow<'data, [u8]>> {
+        let slice = self.read_slice(n)?;
+        if slice.len() != n {
+            return Err(error!(
+                InvalidData,
+                "reader returned {} bytes instead of {}",
+                slice.len(),
+                n
+            ));
+        }
+        Ok(slice)
+    }
+
+    /// Reads `expected.len()` bytes from this reader and checks that they are
+    /// equal to `expected`.
+    ///
+    /// This is meant for literal byte sequences, such as the magic number at
+    /// the start of a file format, and reports a mismatch with both sequences.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the bytes read are
+    /// not equal to `expected`. In this case, they are consumed regardless.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_slice()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{ErrorKind, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"\x7fELF\x7fELG";
+    /// reader.consume_expected(b"\x7fELF")?;
+    ///
+    /// let error = reader.consume_expected(b"\x7fELF").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "expected magic [7f, 45, 4c, 46], found [7f, 45, 4c, 47]",
+    /// );
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn consume_expected(&mut self, expected: &[u8]) -> Result<()> {
+        let found = self.read_slice(expected.len())?;
+        if *found != *expected {
+            return Err(error!(
+                InvalidData,
+                "expected magic {:02x?}, found {:02x?}", expected, &*found
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reads the tag byte of a tagged union from this reader.
+    ///
+    /// This is [`read_next()`] under a name that states the intent. When the
+    /// tag turns out to be unrecognized, report it with
+    /// [`Error::unknown_tag`], so that every tagged union produces the same
+    /// error message.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_next()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Error, ErrorKind, Read, ReadExt, SliceReader};
+    ///
+    /// enum Shape {
+    ///     Circle(u8),
+    ///     Square(u8),
+    /// }
+    ///
+    /// fn read_shape(reader: &mut SliceReader<'_>) -> zc_io::Result<Shape> {
+    ///     let offset = reader.offset();
+    ///     match reader.read_tag()? {
+    ///         0 => Ok(Shape::Circle(reader.read_next()?)),
+    ///         1 => Ok(Shape::Square(reader.read_next()?)),
+    ///         tag => Err(Error::unknown_tag(tag, offset as u64)),
+    ///     }
+    /// }
+    ///
+    /// let mut reader = SliceReader::new(b"\x00\x05\x07");
+    /// assert!(matches!(read_shape(&mut reader)?, Shape::Circle(5)));
+    /// let error = read_shape(&mut reader).err().unwrap();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_next()`]: Read::read_next
+    /// [`Error::unknown_tag`]: crate::Error::unknown_tag
+    #[inline]
+    fn read_tag(&mut self) -> Result<u8> {
+        self.read_next()
+    }
+
+    /// Reads `count` records of `N` bytes each from this reader.
+    ///
+    /// All `count * N` bytes are read with a single call to
+    /// [`read_slice_exact()`], and then split into records. For in-memory
+    /// sources, that is a single borrow, while an [`IoReader<R>`] reads them
+    /// in one go instead of once per record. As the bytes are requested through
+    /// [`read_slice()`], a [`GuardedReader<R>`] applies its limit to all of
+    /// them at once.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `count * N`
+    /// overflows a `usize`.
+    ///
+    /// Otherwise, this method returns the same errors as
+    /// [`read_slice_exact()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::ReadExt;
+    ///
+    /// let mut reader: &[u8] = b"abcdefg";
+    /// assert_eq!(reader.read_arrays::<2>(3)?, [*b"ab", *b"cd", *b"ef"]);
+    /// assert!(reader.read_arrays::<4>(usize::MAX).is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice_exact()`]: ReadExt::read_slice_exact
+    /// [`IoReader<R>`]: crate::IoReader
+    /// [`read_slice()`]: Read::read_slice
+    /// [`GuardedReader<R>`]: crate::GuardedReader
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_arrays<const N: usize>(&mut self, count: usize) -> Result<Vec<[u8; N]>> {
+        let len = count
+            .checked_mul(N)
+            .ok_or_else(|| error!(InvalidInput, "slice length overflows usize"))?;
+
+        let bytes = self.read_slice_exact(len)?;
+        let mut records = vec![[0; N]; count];
+        records.as_flattened_mut().copy_from_slice(&bytes);
+        Ok(records)
+    }
+
+    /// Reads a `count_bytes` wide count in the byte order `O`, followed by
+    /// that many elements, each parsed by `f`.
+    ///
+    /// This covers formats that prefix a sequence of variable-sized elements,
+    /// such as the entries of a map, with their number. The count can't be
+    /// trusted, so the elements reserved upfront take up no more memory than
+    /// there are bytes left according to [`remaining_hint()`], and never more
+    /// than 64 KiB. Beyond that, the vector grows as elements are actually
+    /// read, so a corrupt count fails once the reader runs out, instead of
+    /// allocating memory for it.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `count_bytes` isn't
+    /// between 1 and 8, and an [`ErrorKind::InvalidData`] error if the count
+    /// exceeds the addressable memory of the target.
+    ///
+    /// Otherwise, this method returns the same errors as
+    /// [`read_exact_into()`], and the first error returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BigEndian, Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"\x00\x02\x01a\x03bcd";
+    /// let entries = reader.read_count_prefixed::<BigEndian, _, _>(2, |reader| {
+    ///     let len = reader.read_next()?;
+    ///     Ok(reader.read_slice_exact(len.into())?.into_owned())
+    /// })?;
+    /// assert_eq!(entries, [b"a".to_vec(), b"bcd".to_vec()]);
+    ///
+    /// let mut reader: &[u8] = b"\xff\xff\xff\xff\x00";
+    /// assert!(reader
+    ///     .read_count_prefixed::<BigEndian, _, _>(4, |reader| reader.read_next())
+    ///     .is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// The reservation is bounded in bytes, so a corrupt count doesn't
+    /// allocate memory for large elements either:
+    ///
+    /// ```
+    /// use zc_io::{LittleEndian, Read, ReadExt};
+    ///
+    /// let mut data = vec![0; 4 + (1 << 20)];
+    /// data[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    ///
+    /// let mut reader = &data[..];
+    /// let result = reader.read_count_prefixed::<LittleEndian, _, _>(4, |reader| {
+    ///     reader.read_array::<{ 1 << 16 }>()
+    /// });
+    /// assert!(result.unwrap_err().is_eof());
+    /// ```
+    ///
+    /// [`remaining_hint()`]: Read::remaining_hint
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`read_exact_into()`]: Read::read_exact_into
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_count_prefixed<O, T, F>(&mut self, count_bytes: usize, mut f: F) -> Result<Vec<T>>
+    where
+        O: ByteOrder,
+        F: FnMut(&mut Self) -> Result<T>,
+    {
+        if !(1..=8).contains(&count_bytes) {
+            return Err(error!(
+                InvalidInput,
+                "count width must be between 1 and 8 bytes"
+            ));
+        }
+
+        // The count is read into the least significant bytes of a `u64`, which
+        // are the first ones in little-endian byte order, and the last ones
+        // otherwise.
+        let mut bytes = [0; 8];
+        let little_endian = O::to_bytes(1_u64)[0] == 1;
+        let low = if little_endian {
+            &mut bytes[..count_bytes]
+        } else {
+            &mut bytes[8 - count_bytes..]
+        };
+        self.read_exact_into(low)?;
+        let count = addressable(O::from_bytes::<u64>(bytes))?;
+
+        let bytes = cmp::min(self.remaining_hint().unwrap_or(0), MAX_PREALLOC_BYTES);
+        let mut elements = Vec::with_capacity(cmp::min(count, bytes / mem::size_of::<T>().max(1)));
+        for _ in 0..count {
+            elements.push(f(self)?);
+        }
+        Ok(elements)
+    }
+
+    /// Reads `count` values of the plain-old-data type `T` from this reader,
+    /// borrowing them if possible.
+    ///
+    /// The values are only borrowed if [`read_slice()`] borrows the
+    /// `count * size_of::<T>()` bytes they consist of, and those bytes are
+    /// correctly aligned for `T`. Reinterpreting misaligned bytes would be
+    /// undefined behavior, so otherwise, the bytes are copied into a newly
+    /// allocated, and correctly aligned, [`Vec<T>`]. The bytes are interpreted
+    /// in native byte order.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if the number of bytes
+    /// to read overflows a `usize`.
+    ///
+    /// Otherwise, this method returns the same errors as
+    /// [`read_slice_exact()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::ReadExt;
+    ///
+    /// let words: [u16; 4] = [1, 2, 3, 4];
+    /// let bytes: &[u8] = bytemuck::cast_slice(&words);
+    ///
+    /// let mut aligned = bytes;
+    /// let values = aligned.read_slice_aligned::<u16>(2)?;
+    /// assert!(matches!(values, Cow::Borrowed(&[1, 2])));
+    ///
+    /// // Starting one byte in, the bytes are misaligned for `u16`, so they are
+    /// // copied instead.
+    /// let mut misaligned = &bytes[1..];
+    /// let values = misaligned.read_slice_aligned::<u16>(3)?;
+    /// assert!(matches!(values, Cow::Owned(_)));
+    /// let expected: Vec<u16> = bytes[1..7]
+    ///     .chunks(2)
+    ///     .map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+    ///     .collect();
+    /// assert_eq!(*values, *expected);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`Vec<T>`]: alloc::vec::Vec
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    /// [`read_slice_exact()`]: ReadExt::read_slice_exact
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bytemuck")))]
+    #[inline]
+    fn read_slice_aligned<T>(&mut self, count: usize) -> Result<Cow<'data, [T]>>
+    where
+        T: Pod,
+    {
+        let len = count
+            .checked_mul(mem::size_of::<T>())
+            .ok_or_else(|| error!(InvalidInput, "slice length overflows usize"))?;
+
+        if count == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+        if len == 0 {
+            return Ok(Cow::Owned(vec![T::zeroed(); count]));
+        }
+
+        match self.read_slice_exact(len)? {
+            Cow::Borrowed(bytes) => match bytemuck::try_cast_slice(bytes) {
+                Ok(values) => Ok(Cow::Borrowed(values)),
+                Err(_) => Ok(Cow::Owned(copy_aligned(bytes, count))),
+            },
+            Cow::Owned(bytes) => Ok(Cow::Owned(copy_aligned(&bytes, count))),
+        }
+    }
+
+    /// Reads `n` bytes from this reader and validates them as UTF-8.
+    ///
+    /// If [`read_slice()`] borrows, so does this method, without allocating.
+    /// If it returns an [`Owned`] value instead, that buffer is reused for the
+    /// returned [`String`], so the bytes are only ever validated once.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the bytes are not
+    /// valid UTF-8. When `alloc` is enabled, its message includes how many
+    /// leading bytes were valid.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_slice()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"hello\xffworld";
+    /// assert!(matches!(reader.read_str(5)?, Cow::Borrowed("hello")));
+    /// assert!(reader.read_str(6).is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_slice()`]: Read::read_slice
+    /// [`Owned`]: Cow::Owned
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_str(&mut self, n: usize) -> Result<Cow<'data, str>> {
+        let bytes = self.read_slice(n)?;
+        cow_to_str(bytes)
+    }
+
+    /// Reads a NUL-terminated string from this reader and validates it as
+    /// UTF-8.
+    ///
+    /// This is [`read_cstr()`] followed by the same validation as
+    /// [`read_str()`], so it borrows whenever [`read_cstr()`] does.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the bytes are not
+    /// valid UTF-8.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_cstr()`].
+    ///
+    /// [`read_cstr()`]: Read::read_cstr
+    /// [`read_str()`]: ReadExt::read_str
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_cstr_str(&mut self) -> Result<Cow<'data, str>> {
+        let bytes = self.read_cstr()?;
+        cow_to_str(bytes)
+    }
+
+    /// Reads a boolean encoded as a single byte, which must be `0` or `1`.
+    ///
+    /// Use [`read_bool_lenient()`] for formats that treat any nonzero byte as
+    /// `true`.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the byte is neither
+    /// `0` nor `1`. In this case, the byte is still consumed.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{ErrorKind, Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = &[0, 1, 2];
+    /// assert!(!reader.read_bool()?);
+    /// assert!(reader.read_bool()?);
+    /// assert_eq!(reader.read_bool().unwrap_err().kind(), ErrorKind::InvalidData);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_bool_lenient()`]: ReadExt::read_bool_lenient
+    /// [`read_next()`]: Read::read_next
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[inline]
+    fn read_bool(&mut self) -> Result<bool> {
+        match self.read_next()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            #[cfg(feature = "alloc")]
+            byte => Err(error!(InvalidData, "invalid boolean byte {:#04x}", byte)),
+            #[cfg(not(feature = "alloc"))]
+            _ => Err(error!(InvalidData, "invalid boolean byte")),
+        }
+    }
+
+    /// Reads a boolean encoded as a single byte, where `0` is `false` and any
+    /// other byte is `true`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_next()`].
+    ///
+    /// [`read_next()`]: Read::read_next
+    #[inline]
+    fn read_bool_lenient(&mut self) -> Result<bool> {
+        Ok(self.read_next()? != 0)
+    }
+
+    read_int_fns! {
+        read_u16_le -> u16, from_le_bytes, "little";
+        read_u16_be -> u16, from_be_bytes, "big";
+        read_u32_le -> u32, from_le_bytes, "little";
+        read_u32_be -> u32, from_be_bytes, "big";
+        read_u64_le -> u64, from_le_bytes, "little";
+        read_u64_be -> u64, from_be_bytes, "big";
+        read_u128_le -> u128, from_le_bytes, "little";
+        read_u128_be -> u128, from_be_bytes, "big";
+        read_i16_le -> i16, from_le_bytes, "little";
+        read_i16_be -> i16, from_be_bytes, "big";
+        read_i32_le -> i32, from_le_bytes, "little";
+        read_i32_be -> i32, from_be_bytes, "big";
+        read_i64_le -> i64, from_le_bytes, "little";
+        read_i64_be -> i64, from_be_bytes, "big";
+        read_i128_le -> i128, from_le_bytes, "little";
+        read_i128_be -> i128, from_be_bytes, "big";
+        read_f32_le -> f32, from_le_bytes, "little";
+        read_f32_be -> f32, from_be_bytes, "big";
+        read_f64_le -> f64, from_le_bytes, "little";
+        read_f64_be -> f64, from_be_bytes, "big";
+    }
+
+    /// Reads a [`Primitive`] integer `T` stored in the [`ByteOrder`] `O`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_array()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BigEndian, LittleEndian, ReadExt};
+    ///
+    /// let mut reader: &[u8] = &[0x12, 0x34, 0x12, 0x34];
+    /// assert_eq!(reader.read_int::<u16, BigEndian>()?, 0x1234);
+    /// assert_eq!(reader.read_int::<i16, LittleEndian>()?, 0x3412);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_array()`]: Read::read_array
+    #[inline]
+    fn read_int<T, O>(&mut self) -> Result<T>
+    where
+        T: Primitive,
+        O: ByteOrder,
+    {
+        let bytes = T::read_bytes(self)?;
+        Ok(O::from_bytes(bytes))
+    }
+
+    /// Reads a [`Wrapped`] value `T`, whose type determines its byte order.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_array()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Be, Le, ReadExt};
+    ///
+    /// struct Header {
+    ///     magic: Be<u32>,
+    ///     len: Le<u16>,
+    /// }
+    ///
+    /// let mut reader: &[u8] = b"\x7fELF\x02\x01";
+    /// let header = Header {
+    ///     magic: reader.read_wrapped()?,
+    ///     len: reader.read_wrapped()?,
+    /// };
+    /// assert_eq!(header.magic, Be(0x7f45_4c46));
+    /// assert_eq!(u16::from(header.len), 0x0102);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_array()`]: Read::read_array
+    #[inline]
+    fn read_wrapped<T>(&mut self) -> Result<T>
+    where
+        T: Wrapped,
+    {
+        T::read_from(self)
+    }
+
+    /// Reads a [`Float`] `F` stored in the [`ByteOrder`] `O`.
+    ///
+    /// The bytes are reinterpreted as the bit pattern of `F` without any
+    /// normalization, so subnormals, infinities, and NaNs, including
+    /// signaling NaNs and their payloads, are read exactly as stored.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`read_array()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BigEndian, LittleEndian, ReadExt};
+    ///
+    /// let subnormal = f32::from_bits(0x0000_0001);
+    /// let signaling_nan = f32::from_bits(0x7f80_0001);
+    ///
+    /// let mut bytes = Vec::new();
+    /// bytes.extend_from_slice(&subnormal.to_le_bytes());
+    /// bytes.extend_from_slice(&f64::NEG_INFINITY.to_be_bytes());
+    /// bytes.extend_from_slice(&signaling_nan.to_be_bytes());
+    ///
+    /// let mut reader = &bytes[..];
+    /// assert_eq!(reader.read_float::<f32, LittleEndian>()?, subnormal);
+    /// assert_eq!(reader.read_float::<f64, BigEndian>()?, f64::NEG_INFINITY);
+    /// assert_eq!(reader.read_f32_be()?.to_bits(), 0x7f80_0001);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`read_array()`]: Read::read_array
+    #[inline]
+    fn read_float<F, O>(&mut self) -> Result<F>
+    where
+        F: Float,
+        O: ByteOrder,
+    {
+        self.read_int::<F, O>()
+    }
+
+    /// Reads an unsigned [LEB128]-encoded [`u64`] from this reader.
+    ///
+    /// Bytes are read with [`read_next()`] until one without the continuation
+    /// bit (`0x80`) is found. A [`u64`] takes at most 10 bytes to encode.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the encoded value
+    /// overflows a [`u64`] or if the encoding is longer than 10 bytes.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`],
+    /// including [`ErrorKind::UnexpectedEof`] if the source ends in the middle
+    /// of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = &[0xe5, 0x8e, 0x26];
+    /// assert_eq!(reader.read_uleb128()?, 624_485);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`read_next()`]: Read::read_next
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[inline]
+    fn read_uleb128(&mut self) -> Result<u64> {
+        let value = leb128::read_unsigned(self, u64::BITS)?;
+        // `read_unsigned` guarantees the value fits in 64 bits.
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(value as u64)
+    }
+
+    /// Reads an unsigned [LEB128]-encoded [`u128`] from this reader.
+    ///
+    /// This is identical to [`read_uleb128()`], except that a [`u128`] takes
+    /// at most 19 bytes to encode.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the encoded value
+    /// overflows a [`u128`] or if the encoding is longer than 19 bytes.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`],
+    /// including [`ErrorKind::UnexpectedEof`] if the source ends in the middle
+    /// of the value.
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`read_uleb128()`]: ReadExt::read_uleb128
+    /// [`read_next()`]: Read::read_next
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[inline]
+    fn read_uleb128_u128(&mut self) -> Result<u128> {
+        leb128::read_unsigned(self, u128::BITS)
+    }
+
+    /// Reads a signed [LEB128]-encoded [`i64`] from this reader.
+    ///
+    /// The value is sign-extended from the last group read when that group's
+    /// sign bit (`0x40`) is set. This is the encoding used by DWARF and
+    /// WebAssembly, not zigzag encoding. An [`i64`] takes at most 10 bytes to
+    /// encode.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the encoded value
+    /// overflows an [`i64`] or if the encoding is longer than 10 bytes.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`],
+    /// including [`ErrorKind::UnexpectedEof`] if the source ends in the middle
+    /// of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, ReadExt};
+    ///
+    /// let mut reader: &[u8] = &[0x7f];
+    /// assert_eq!(reader.read_sleb128()?, -1);
+    ///
+    /// let mut reader: &[u8] = &[0x80, 0x7f];
+    /// assert_eq!(reader.read_sleb128()?, -128);
+    ///
+    /// let mut reader: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f];
+    /// assert_eq!(reader.read_sleb128()?, i64::MIN);
+    ///
+    /// let mut reader: &[u8] = &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+    /// assert_eq!(reader.read_sleb128()?, i64::MAX);
+    ///
+    /// // The tenth byte may only hold the sign of the value.
+    /// let mut reader: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x3f];
+    /// assert!(reader.read_sleb128().is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`read_next()`]: Read::read_next
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[inline]
+    fn read_sleb128(&mut self) -> Result<i64> {
+        let value = leb128::read_signed(self, i64::BITS)?;
+        // `read_signed` guarantees the value fits in 64 bits.
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(value as i64)
+    }
+
+    read_prefixed_fns! {
+        read_u8_prefixed -> read_next, "[`u8`]";
+        read_u16_le_prefixed -> read_u16_le, "little-endian [`u16`]";
+        read_u16_be_prefixed -> read_u16_be, "big-endian [`u16`]";
+        read_u32_le_prefixed -> read_u32_le, "little-endian [`u32`]";
+        read_u32_be_prefixed -> read_u32_be, "big-endian [`u32`]";
+        read_u64_le_prefixed -> read_u64_le, "little-endian [`u64`]";
+        read_u64_be_prefixed -> read_u64_be, "big-endian [`u64`]";
+    }
+
+    /// Reads an unsigned [LEB128]-encoded length, followed by that many bytes,
+    /// borrowing them if possible.
+    ///
+    /// Like every length-prefixed read, this preserves borrowing for
+    /// in-memory sources. As the length comes from the source, a corrupt or
+    /// malicious one may ask for far more bytes than exist. If the
+    /// [`remaining_hint()`] of this reader is smaller than the length, this
+    /// fails before anything is allocated. Otherwise, wrap readers of
+    /// untrusted input in a [`GuardedReader<R>`] to reject such lengths.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if the length is not a
+    /// valid [LEB128] encoding of a [`u64`], or if it exceeds the addressable
+    /// memory of the target. An [`ErrorKind::UnexpectedEof`] error is returned
+    /// if the length exceeds the [`remaining_hint()`] of this reader.
+    ///
+    /// Otherwise, this method returns the same errors as [`read_next()`] and
+    /// [`read_slice()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use zc_io::{ErrorKind, GuardedReader, ReadExt};
+    ///
+    /// let mut reader: &[u8] = b"\x05hello\x00\x00\x00\x05world";
+    /// assert!(matches!(reader.read_uleb128_prefixed()?, Cow::Borrowed(b"hello")));
+    /// assert_eq!(reader.read_u32_be_prefixed()?.as_ref(), b"world");
+    ///
+    /// let mut reader = b"\x05hello".take(4);
+    /// let error = reader.read_uleb128_prefixed().unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    ///
+    /// let mut reader = GuardedReader::new(&b"\xff\xff\xff\xff\x0f"[..], 1024);
+    /// assert!(reader.read_uleb128_prefixed().is_err());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// A length of 2<sup>32</sup> bytes is never truncated, even where it
+    /// doesn't fit in a [`usize`]:
+    ///
+    /// ```
+    /// use zc_io::{ErrorKind, ReadExt};
+    ///
+    /// let mut reader: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x10];
+    /// let error = reader.read_uleb128_prefixed().unwrap_err();
+    ///
+    /// if cfg!(target_pointer_width = "32") {
+    ///     assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// } else {
+    ///     assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    /// }
+    /// ```
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`remaining_hint()`]: Read::remaining_hint
+    /// [`GuardedReader<R>`]: crate::GuardedReader
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    /// [`read_next()`]: Read::read_next
+    /// [`read_slice()`]: Read::read_slice
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn read_uleb128_prefixed(&mut self) -> Result<Cow<'data, [u8]>> {
+        let len = self.read_uleb128()?;
+        read_prefixed(self, len)
+    }
+}
+
+impl<'data, R> ReadExt<'data> for R where R: ?Sized + Read<'data> {}
@@ -0,0 +1,150 @@
+use crate::{Be, Le, Primitive, Read, Result};
+
+use core::array;
+
+/// A type with a fixed binary layout that can be read from any
+/// [`Read<'data>`].
+///
+/// This trait is implemented for `u8`, `i8`, [`Le<T>`], and [`Be<T>`], whose
+/// layouts are unambiguous, and for arrays of any of them. Wider primitive
+/// numeric types don't implement it, since their byte order has to be chosen
+/// first.
+///
+/// With the `derive` feature, `#[derive(ReadFrom)]` implements this trait for
+/// structs by reading their fields in declaration order, so structs deriving
+/// it can be nested in one another.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Be, Read, ReadExt, ReadFrom, Result};
+///
+/// struct Version {
+///     major: u8,
+///     minor: u8,
+///     build: Be<u16>,
+/// }
+///
+/// impl ReadFrom for Version {
+///     fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+///     where
+///         R: ?Sized + Read<'data>,
+///     {
+///         Ok(Version {
+///             major: u8::read_from(reader)?,
+///             minor: u8::read_from(reader)?,
+///             build: reader.read_wrapped()?,
+///         })
+///     }
+/// }
+///
+/// let mut reader: &[u8] = &[1, 2, 0x01, 0x00];
+/// let version = Version::read_from(&mut reader)?;
+/// assert_eq!((version.major, version.minor, version.build.0), (1, 2, 256));
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+pub trait ReadFrom: Sized {
+    /// Reads a value of this type from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the errors of the reads it performs, and possibly
+    /// an [`ErrorKind::InvalidData`] error if the bytes read don't form a
+    /// valid value.
+    ///
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>;
+
+    #[doc(hidden)]
+    #[inline]
+    fn read_array_from<'data, R, const N: usize>(reader: &mut R) -> Result<[Self; N]>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        let mut error = None;
+        let array: [Option<Self>; N] = array::from_fn(|_| {
+            if error.is_some() {
+                return None;
+            }
+            Self::read_from(reader)
+                .map_err(|err| error = Some(err))
+                .ok()
+        });
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(array.map(|element| element.expect("every element was read"))),
+        }
+    }
+}
+
+impl ReadFrom for u8 {
+    #[inline]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        reader.read_next()
+    }
+
+    #[inline]
+    fn read_array_from<'data, R, const N: usize>(reader: &mut R) -> Result<[Self; N]>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        reader.read_array()
+    }
+}
+
+impl ReadFrom for i8 {
+    #[inline]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        Ok(i8::from_ne_bytes(reader.read_array()?))
+    }
+}
+
+impl<T, const N: usize> ReadFrom for [T; N]
+where
+    T: ReadFrom,
+{
+    #[inline]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        T::read_array_from(reader)
+    }
+}
+
+impl<T> ReadFrom for Le<T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        Le::read(reader)
+    }
+}
+
+impl<T> ReadFrom for Be<T>
+where
+    T: Primitive,
+{
+    #[inline]
+    fn read_from<'data, R>(reader: &mut R) -> Result<Self>
+    where
+        R: ?Sized + Read<'data>,
+    {
+        Be::read(reader)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Peek, Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec};
+
+/// A reader which yields one byte over and over.
+///
+/// This struct is generally created by calling [`repeat`]. Please see the
+/// documentation of [`repeat`] for more details.
+#[derive(Clone, Copy, Debug)]
+pub struct Repeat {
+    byte: u8,
+}
+
+/// Creates a reader that infinitely repeats one byte.
+///
+/// All reads from the returned [`Repeat`] succeed, and it never reaches
+/// end-of-file. Methods that read until end-of-file or until a delimiter that
+/// is never found, like [`read_to_end`], therefore never return.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::Read;
+///
+/// let mut reader = zc_io::repeat(0xAB);
+/// assert_eq!(reader.read_next()?, 0xAB);
+/// assert_eq!(reader.read_array()?, [0xAB; 3]);
+///
+/// let mut buf = [0; 2];
+/// reader.read_exact_into(&mut buf)?;
+/// assert_eq!(buf, [0xAB; 2]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`read_to_end`]: Read::read_to_end
+#[must_use]
+#[inline]
+pub const fn repeat(byte: u8) -> Repeat {
+    Repeat { byte }
+}
+
+#[cfg_attr(not(feature = "alloc"), allow(clippy::elidable_lifetime_names))]
+impl<'data> Read<'data> for Repeat {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        Ok(self.byte)
+    }
+
+    /// Returns an [`Owned`] value of `len` copies of the byte, unless `len` is
+    /// zero.
+    ///
+    /// [`Owned`]: Cow::Owned
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        Ok(Cow::Owned(vec![self.byte; len]))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok([self.byte; N])
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        buf.fill(self.byte);
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, _: usize) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Peek<'_> for Repeat {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok([self.byte; N])
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{Peek, Read, ReadRef, Result, SliceReader};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+/// A reader over a sized section of input which must be consumed entirely.
+///
+/// Formats often declare the length of a chunk upfront, and a parser that
+/// reads too little of it silently misaligns every read after the chunk. A
+/// `ScopedReader<'data>` reads from such a section, and [`finish`] reports an
+/// error if any of its bytes were left unread.
+///
+/// This struct is generally created by calling [`read_scoped`] on a reader.
+/// As it borrows from the section like a [`SliceReader<'data>`], the reader it
+/// was split off from can keep going independently.
+///
+/// Nothing is checked when a `ScopedReader<'data>` is dropped, as a parser
+/// that returns early, such as on a validation error, rightfully leaves its
+/// section unread. Call [`finish`] wherever a section must have been read
+/// entirely, or [`into_inner`] to keep reading what's left of it.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{ErrorKind, Read, ReadRef};
+///
+/// let mut reader: &[u8] = b"\x04abcd\x03xyz";
+///
+/// let len = reader.read_next()?;
+/// let mut chunk = reader.read_scoped(len.into())?;
+/// assert_eq!(chunk.read_array()?, *b"abcd");
+/// chunk.finish()?;
+///
+/// let len = reader.read_next()?;
+/// let mut chunk = reader.read_scoped(len.into())?;
+/// assert_eq!(chunk.read_array()?, *b"xy");
+/// let error = chunk.finish().unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::InvalidData);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`finish`]: ScopedReader::finish
+/// [`read_scoped`]: ReadRef::read_scoped
+/// [`SliceReader<'data>`]: SliceReader
+/// [`into_inner`]: ScopedReader::into_inner
+#[derive(Debug)]
+pub struct ScopedReader<'data> {
+    inner: SliceReader<'data>,
+}
+
+impl<'data> ScopedReader<'data> {
+    /// Creates a new `ScopedReader<'data>` whose section is everything that
+    /// remains in `inner`.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: SliceReader<'data>) -> Self {
+        ScopedReader { inner }
+    }
+
+    /// Returns the number of bytes read from the start of the section.
+    #[must_use]
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+
+    /// Returns the bytes of the section that have yet to be read.
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> &'data [u8] {
+        self.inner.remaining()
+    }
+
+    /// Ends the section, checking that all of its bytes were read.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidData`] error is returned if any bytes of the
+    /// section remain unread. With `alloc`, its message includes how many.
+    ///
+    /// [`ErrorKind::InvalidData`]: crate::ErrorKind::InvalidData
+    #[inline]
+    pub fn finish(self) -> Result<()> {
+        let left = self.into_inner().remaining().len();
+        if left > 0 {
+            #[cfg(feature = "alloc")]
+            return Err(error!(InvalidData, "{} bytes left unread in section", left));
+            #[cfg(not(feature = "alloc"))]
+            return Err(error!(InvalidData, "bytes left unread in section"));
+        }
+        Ok(())
+    }
+
+    /// Unwraps the `ScopedReader<'data>` without checking that its section was
+    /// read entirely, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> SliceReader<'data> {
+        self.inner
+    }
+}
+
+impl<'data> Read<'data> for ScopedReader<'data> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.inner.read_next()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_slice(len)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.inner.read_array()
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact_into(buf)
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.inner.skip(n)
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        self.inner.remaining_hint()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_cstr()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_until(delim)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.inner.read_remaining()
+    }
+}
+
+impl<'data> Peek<'data> for ScopedReader<'data> {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.inner.peek_array()
+    }
+}
+
+impl<'data> ReadRef<'data> for ScopedReader<'data> {
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        self.inner.read_array_ref()
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        self.inner.read_sub(n)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Cursor, Result, SliceReader};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::io;
+
+/// A convenient alias for [`io::SeekFrom`].
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub type SeekFrom = io::SeekFrom;
+
+/// Enumeration of possible methods to seek within a reader.
+///
+/// It is used by the [`Seek`] trait. When `std` is enabled, this is an alias
+/// for `std::io::SeekFrom` instead.
+#[cfg(not(feature = "std"))]
+#[derive(Copy, PartialEq, Eq, Clone, Debug, Hash)]
+pub enum SeekFrom {
+    /// Sets the offset to the provided number of bytes.
+    Start(u64),
+
+    /// Sets the offset to the size of this object plus the specified number of
+    /// bytes.
+    ///
+    /// It is possible to seek beyond the end of an object, but it's an error to
+    /// seek before byte 0.
+    End(i64),
+
+    /// Sets the offset to the current position plus the specified number of
+    /// bytes.
+    ///
+    /// It is possible to seek beyond the end of an object, but it's an error to
+    /// seek before byte 0.
+    Current(i64),
+}
+
+/// The `Seek` trait provides a cursor which can be moved within a stream of
+/// bytes.
+///
+/// Unlike [`io::Seek`], this trait is available in `no_std` environments, and
+/// is implemented by the in-memory readers of [`zc_io`]. When `std` is
+/// enabled, it is also implemented by [`IoReader<R>`] and [`IoWriter<W>`] for
+/// any `R` or `W` that implements [`io::Seek`].
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Cursor, Read, Seek, SeekFrom};
+///
+/// let data = b"header:payload";
+/// let mut cursor = Cursor::new(&data[..]);
+///
+/// assert_eq!(cursor.seek(SeekFrom::Start(7))?, 7);
+/// assert_eq!(cursor.read_array()?, *b"pay");
+/// assert_eq!(cursor.seek(SeekFrom::End(-1))?, 13);
+/// assert!(cursor.seek(SeekFrom::Current(-20)).is_err());
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`io::Seek`]: https://doc.rust-lang.org/std/io/trait.Seek.html
+/// [`zc_io`]: crate
+/// [`IoReader<R>`]: crate::IoReader
+/// [`IoWriter<W>`]: crate::IoWriter
+pub trait Seek {
+    /// Seeks to an offset, in bytes, in a stream.
+    ///
+    /// If the seek operation completed successfully, this method returns the
+    /// new position from the start of the stream.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned when seeking to a
+    /// negative offset, or to an offset that cannot be represented.
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64>;
+}
+
+impl<S> Seek for &mut S
+where
+    S: ?Sized + Seek,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        (**self).seek(pos)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<S> Seek for Box<S>
+where
+    S: ?Sized + Seek,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        (**self).seek(pos)
+    }
+}
+
+/// Resolves `pos` into an absolute offset, given the `current` offset and the
+/// `len` of the stream.
+fn resolve(pos: SeekFrom, current: usize, len: usize) -> Result<usize> {
+    let (base, delta) = match pos {
+        SeekFrom::Start(offset) => return usize::try_from(offset).map_err(|_| invalid_seek()),
+        SeekFrom::End(delta) => (len, delta),
+        SeekFrom::Current(delta) => (current, delta),
+    };
+
+    isize::try_from(delta)
+        .ok()
+        .and_then(|delta| base.checked_add_signed(delta))
+        .ok_or_else(invalid_seek)
+}
+
+fn invalid_seek() -> crate::Error {
+    error!(
+        InvalidInput,
+        "invalid seek to a negative or overflowing position"
+    )
+}
+
+/// Seek is implemented for `&[u8]` by treating the front of the slice as the
+/// start of the stream.
+///
+/// Since bytes before the front of the slice are no longer reachable, seeking
+/// only ever moves forward, discarding bytes, and the new position is always
+/// `0`. Seeking backwards or beyond the end of the slice is an error.
+impl Seek for &[u8] {
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let offset = resolve(pos, 0, self.len())?;
+        match self.get(offset..) {
+            Some(rest) => {
+                *self = rest;
+                Ok(0)
+            }
+            None => Err(invalid_seek()),
+        }
+    }
+}
+
+/// Seek is implemented for [`Cursor<T>`] like it is for `std::io::Cursor<T>`,
+/// so seeking beyond the end of the buffer is allowed.
+///
+/// Combined with the [`Write`] implementation of a cursor, this allows
+/// back-patching: seeking backwards and writing overwrites only as many bytes
+/// as are written, leaving the rest of the buffer intact.
+///
+/// ```
+/// use zc_io::{Cursor, Seek, SeekFrom, Write, WriteExt};
+///
+/// let mut buf = [0; 8];
+/// let mut cursor = Cursor::new(&mut buf[..]);
+/// cursor.write_u32_le(0)?;
+/// cursor.write_all(b"body")?;
+///
+/// let end = cursor.position();
+/// cursor.seek(SeekFrom::Start(0))?;
+/// cursor.write_u32_le((end - 4) as u32)?;
+/// cursor.seek(SeekFrom::Start(end as u64))?;
+///
+/// assert_eq!(buf, *b"\x04\0\0\0body");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Write`]: crate::Write
+impl<T> Seek for Cursor<T>
+where
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let offset = resolve(pos, self.position(), self.get_ref().as_ref().len())?;
+        self.set_position(offset);
+        Ok(offset as u64)
+    }
+}
+
+/// Seek is implemented for [`SliceReader<'data>`] by moving its offset within
+/// the original slice. Seeking beyond the end of the slice is an error.
+///
+/// [`SliceReader<'data>`]: SliceReader
+impl Seek for SliceReader<'_> {
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let offset = resolve(pos, self.offset(), self.get_ref().len())?;
+        self.seek_to(offset)?;
+        Ok(offset as u64)
+    }
+}
@@ -0,0 +1,153 @@
+//! Helpers for moving byte blobs between [`zc_io`] and [`serde`] without
+//! copying.
+//!
+//! A blob is stored as its length, encoded as an unsigned [LEB128] value,
+//! followed by its bytes. [`read_bytes`] returns a [`Borrowed`] blob whenever
+//! the underlying reader borrows, such as `&[u8]`, [`Cursor<&'data T>`], or
+//! [`SliceReader<'data>`], so a borrowing deserializer can be handed bytes
+//! that live for `'data`. [`write_bytes`] writes a blob produced by a
+//! serializer to any [`Write`].
+//!
+//! This module can also be used with `#[serde(with = "zc_io::serde")]` on a
+//! `Cow<'data, [u8]>` field. It serializes the field as bytes, just like
+//! `serde_bytes` does, and deserializes it as a [`Borrowed`] value whenever the
+//! deserializer can lend out bytes that live long enough.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use serde::de::value::{BorrowedBytesDeserializer, Error};
+//!
+//! let mut source: &[u8] = b"\x05hello";
+//! let blob = zc_io::serde::read_bytes(&mut source)?;
+//! assert!(matches!(blob, Cow::Borrowed(b"hello")));
+//!
+//! let deserializer = BorrowedBytesDeserializer::<Error>::new(&blob);
+//! let field: Cow<'_, [u8]> = zc_io::serde::deserialize(deserializer).unwrap();
+//! assert!(matches!(field, Cow::Borrowed(b"hello")));
+//! # Ok::<(), zc_io::Error>(())
+//! ```
+//!
+//! [`zc_io`]: crate
+//! [LEB128]: https://en.wikipedia.org/wiki/LEB128
+//! [`Borrowed`]: Cow::Borrowed
+//! [`Cursor<&'data T>`]: crate::Cursor
+//! [`SliceReader<'data>`]: crate::SliceReader
+
+use crate::{Read, ReadExt, Result, Write, WriteExt};
+
+use ::serde::{
+    de::{SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt::{self, Formatter};
+
+/// Reads a length-prefixed byte blob from `reader`.
+///
+/// This is the same as [`read_uleb128_prefixed`].
+///
+/// # Errors
+///
+/// This function returns the same errors as [`read_uleb128_prefixed`].
+///
+/// [`read_uleb128_prefixed`]: ReadExt::read_uleb128_prefixed
+#[inline]
+pub fn read_bytes<'data, R>(reader: &mut R) -> Result<Cow<'data, [u8]>>
+where
+    R: ?Sized + Read<'data>,
+{
+    reader.read_uleb128_prefixed()
+}
+
+/// Writes `bytes` to `writer` as a length-prefixed byte blob.
+///
+/// # Errors
+///
+/// This function returns the same errors as [`write_all`].
+///
+/// [`write_all`]: Write::write_all
+#[inline]
+pub fn write_bytes<W>(writer: &mut W, bytes: &[u8]) -> Result<()>
+where
+    W: ?Sized + Write,
+{
+    writer.write_uleb128(bytes.len() as u64)?;
+    writer.write_all(bytes)
+}
+
+/// Serializes `bytes` with [`Serializer::serialize_bytes`].
+///
+/// # Errors
+///
+/// This function returns the same errors as the serializer.
+#[inline]
+pub fn serialize<T, S>(bytes: &T, serializer: S) -> core::result::Result<S::Ok, S::Error>
+where
+    T: ?Sized + AsRef<[u8]>,
+    S: Serializer,
+{
+    serializer.serialize_bytes(bytes.as_ref())
+}
+
+/// Deserializes a byte buffer, borrowing it from the deserializer if possible.
+///
+/// Byte strings and strings that live for `'de` are returned as [`Borrowed`]
+/// values. Any other bytes, including a sequence of integers, are collected
+/// into an [`Owned`] value.
+///
+/// # Errors
+///
+/// This function returns the same errors as the deserializer.
+///
+/// [`Borrowed`]: Cow::Borrowed
+/// [`Owned`]: Cow::Owned
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> core::result::Result<Cow<'de, [u8]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(CowBytesVisitor)
+}
+
+struct CowBytesVisitor;
+
+impl<'de> Visitor<'de> for CowBytesVisitor {
+    type Value = Cow<'de, [u8]>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v.as_bytes()))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Owned(v.to_vec()))
+    }
+
+    fn visit_str<E>(self, v: &str) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Owned(v.as_bytes().to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Owned(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(Cow::Owned(bytes))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{IoSlice, Result, Write};
+
+/// A writer which discards all bytes written to it.
+///
+/// This struct is generally created by calling [`sink`]. Please see the
+/// documentation of [`sink`] for more details.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sink {
+    _private: (),
+}
+
+/// Creates a writer that successfully discards all bytes written to it.
+///
+/// Combined with a [`CountingWriter<W>`], this measures how many bytes a
+/// serializer produces without storing them.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{CountingWriter, Write, WriteExt};
+///
+/// let mut writer = CountingWriter::new(zc_io::sink());
+/// writer.write_all(b"discarded")?;
+/// writer.write_u32_le(7)?;
+/// assert_eq!(writer.count(), 13);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`CountingWriter<W>`]: crate::CountingWriter
+#[must_use]
+#[inline]
+pub const fn sink() -> Sink {
+    Sink { _private: () }
+}
+
+impl Write for Sink {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_all(&mut self, _: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        Ok(bufs.iter().map(|buf| buf.len()).sum())
+    }
+
+    #[inline]
+    fn write_all_vectored(&mut self, _: &mut [IoSlice<'_>]) -> Result<()> {
+        Ok(())
+    }
+}
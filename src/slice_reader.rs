@@ -0,0 +1,277 @@
+use crate::{align, BufRead, Peek, Read, ReadRef, Result, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+use core::cmp;
+
+/// A `SliceReader<'data>` reads from a borrowed byte slice while remembering
+/// the slice in its entirety.
+///
+/// Reading from a `&[u8]` directly forgets everything that was consumed. A
+/// `SliceReader<'data>` instead keeps the original slice along with an offset
+/// into it, so it can always tell how far into its input it is, which is the
+/// building block for error messages such as "invalid tag at byte 42".
+///
+/// Every read borrows from the original slice, so the returned [`Borrowed`]
+/// values live for `'data`, independently of the `SliceReader<'data>` itself.
+/// Compared to [`Cursor<T>`], which is generic over its buffer, this type is
+/// specialized for borrowed input.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Read, SliceReader};
+///
+/// let mut reader = SliceReader::new(b"\x01\x02rest");
+/// reader.skip(2)?;
+/// assert_eq!(reader.offset(), 2);
+/// assert_eq!(reader.remaining(), b"rest");
+///
+/// reader.seek_to(1)?;
+/// assert_eq!(reader.read_next()?, 0x02);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Borrowed`]: Cow::Borrowed
+/// [`Cursor<T>`]: crate::Cursor
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SliceReader<'data> {
+    data: &'data [u8],
+    pos: usize,
+}
+
+impl<'data> SliceReader<'data> {
+    /// Creates a new `SliceReader<'data>` positioned at the start of `data`.
+    #[must_use]
+    #[inline]
+    pub fn new(data: &'data [u8]) -> Self {
+        SliceReader { data, pos: 0 }
+    }
+
+    /// Returns the number of bytes read from the start of the original slice.
+    #[must_use]
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the bytes that have yet to be read.
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> &'data [u8] {
+        &self.data[self.pos..]
+    }
+
+    /// Moves this reader to `offset` bytes from the start of the original
+    /// slice.
+    ///
+    /// Seeking backwards is allowed, so previously read bytes may be read
+    /// again.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `offset` lies beyond
+    /// the end of the original slice. In this case, the offset is unchanged.
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    #[inline]
+    pub fn seek_to(&mut self, offset: usize) -> Result<()> {
+        if offset > self.data.len() {
+            return Err(error!(
+                InvalidInput,
+                "offset is beyond the end of the slice"
+            ));
+        }
+
+        self.pos = offset;
+        Ok(())
+    }
+
+    /// Skips past the padding up to the next offset that is a multiple of
+    /// `boundary`, counting from the start of the original slice.
+    ///
+    /// Nothing is skipped if the offset is already aligned.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::InvalidInput`] error is returned if `boundary` is not a
+    /// power of two.
+    ///
+    /// Otherwise, this method returns the same errors as [`skip()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Read, SliceReader};
+    ///
+    /// let mut reader = SliceReader::new(b"abc\0\0\0\0\0defg");
+    /// assert_eq!(reader.read_array()?, *b"abc");
+    /// reader.align_to(8)?;
+    /// assert_eq!(reader.remaining(), b"defg");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::ErrorKind::InvalidInput
+    /// [`skip()`]: Read::skip
+    #[inline]
+    pub fn align_to(&mut self, boundary: usize) -> Result<()> {
+        let padding = align::padding(self.pos as u64, boundary)?;
+        self.skip(padding)
+    }
+
+    /// Gets the original slice this reader was created from.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &'data [u8] {
+        self.data
+    }
+
+    /// Runs `f` on the remaining bytes, then moves past however many bytes `f`
+    /// consumed.
+    #[inline]
+    fn advance<U, F>(&mut self, f: F) -> Result<U>
+    where
+        F: FnOnce(&mut &'data [u8]) -> Result<U>,
+    {
+        let mut rest = self.remaining();
+        let value = f(&mut rest)?;
+        self.pos = self.data.len() - rest.len();
+        Ok(value)
+    }
+}
+
+/// Creates a `SliceReader<'data>` over a slice, an array, or a vector.
+///
+/// A `&Vec<u8>` or a `&[u8; N]` can't implement [`Read<'data>`] itself, since
+/// reading has to advance the reader, and a reference to a vector or an array
+/// can't be shortened the way a `&[u8]` is. Converting them into a
+/// `SliceReader<'data>` reads them without spelling out `.as_slice()`, and the
+/// borrowed slices still live for `'data`, not for the conversion.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Read, ReadRef, SliceReader};
+///
+/// let data = [1, 2, 3];
+/// let mut reader = SliceReader::from(&data[..]);
+/// assert_eq!(reader.read_array_ref()?, &[1, 2]);
+///
+/// let mut reader = SliceReader::from(b"fixture");
+/// assert_eq!(reader.read_array()?, *b"fix");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`Read<'data>`]: Read
+impl<'data> From<&'data [u8]> for SliceReader<'data> {
+    #[inline]
+    fn from(data: &'data [u8]) -> Self {
+        SliceReader::new(data)
+    }
+}
+
+impl<'data, const N: usize> From<&'data [u8; N]> for SliceReader<'data> {
+    #[inline]
+    fn from(data: &'data [u8; N]) -> Self {
+        SliceReader::new(data)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'data> From<&'data Vec<u8>> for SliceReader<'data> {
+    #[inline]
+    fn from(data: &'data Vec<u8>) -> Self {
+        SliceReader::new(data)
+    }
+}
+
+impl<'data> Read<'data> for SliceReader<'data> {
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        self.advance(Read::read_next)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        self.advance(|rest| rest.read_slice(len))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.advance(Read::read_array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.advance(|rest| rest.read_exact_into(buf))
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.advance(|rest| rest.skip(n))
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        Some(self.data.len() - self.pos)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_cstr(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.advance(Read::read_cstr)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, delim: u8) -> Result<Cow<'data, [u8]>> {
+        self.advance(|rest| rest.read_until(delim))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining(&mut self) -> Result<Cow<'data, [u8]>> {
+        self.advance(Read::read_remaining)
+    }
+
+    #[inline]
+    fn copy_to<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: ?Sized + Write,
+    {
+        self.advance(|rest| rest.copy_to(writer))
+    }
+}
+
+impl<'data> Peek<'data> for SliceReader<'data> {
+    #[inline]
+    fn peek_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.remaining().read_array()
+    }
+}
+
+impl<'data> ReadRef<'data> for SliceReader<'data> {
+    #[inline]
+    fn read_array_ref<const N: usize>(&mut self) -> Result<&'data [u8; N]> {
+        self.advance(ReadRef::read_array_ref)
+    }
+
+    #[inline]
+    fn read_sub(&mut self, n: usize) -> Result<SliceReader<'data>> {
+        self.advance(|rest| rest.read_sub(n))
+    }
+}
+
+impl<'data> BufRead<'data> for SliceReader<'data> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos.saturating_add(amt), self.data.len());
+    }
+}
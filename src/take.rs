@@ -0,0 +1,147 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::cmp;
+
+/// A reader adapter which limits the bytes read from an underlying reader.
+///
+/// This struct is generally created by calling [`take`] on a reader. Please
+/// see the documentation of [`take`] for more details.
+///
+/// [`take`]: crate::ReadExt::take
+#[derive(Debug)]
+pub struct Take<R> {
+    inner: R,
+    limit: u64,
+}
+
+impl<R> Take<R> {
+    #[inline]
+    pub(crate) fn new(inner: R, limit: u64) -> Self {
+        Take { inner, limit }
+    }
+
+    /// Returns the number of bytes that can be read before this instance will
+    /// report end-of-file.
+    #[must_use]
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the number of bytes that can be read before this instance will
+    /// report end-of-file. This is the same as constructing a new `Take<R>`
+    /// instance, so the amount of bytes read and the previous limit value
+    /// don't matter when calling this method.
+    #[inline]
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader as doing so may corrupt the internal limit of this
+    /// `Take<R>`.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `Take<R>`, returning the underlying reader.
+    ///
+    /// Note that any leftover limit is lost.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<'data, R> Read<'data> for Take<R>
+where
+    R: Read<'data>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        if self.limit == 0 {
+            return Err(error!(UnexpectedEof, "failed to read byte"));
+        }
+
+        let byte = self.inner.read_next()?;
+        self.limit -= 1;
+        Ok(byte)
+    }
+
+    /// Reads up to `len` bytes from the underlying reader.
+    ///
+    /// If fewer than `len` bytes remain in the limit, the request is clamped
+    /// and a shorter slice is returned. Once the limit is exhausted, an
+    /// [`ErrorKind::UnexpectedEof`] error is returned instead.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: crate::ErrorKind::UnexpectedEof
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if self.limit == 0 && len > 0 {
+            return Err(error!(UnexpectedEof, "failed to read slice"));
+        }
+
+        let len = usize::try_from(self.limit).map_or(len, |limit| cmp::min(len, limit));
+        let slice = self.inner.read_slice(len)?;
+        self.limit = self.limit.saturating_sub(slice.len() as u64);
+        Ok(slice)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.limit < N as u64 {
+            return Err(error!(UnexpectedEof, "failed to read array"));
+        }
+
+        let array = self.inner.read_array()?;
+        self.limit -= N as u64;
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if self.limit < buf.len() as u64 {
+            return Err(error!(UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        self.inner.read_exact_into(buf)?;
+        self.limit -= buf.len() as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        if self.limit < n as u64 {
+            return Err(error!(UnexpectedEof, "failed to skip bytes"));
+        }
+
+        self.inner.skip(n)?;
+        self.limit -= n as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        let limit = usize::try_from(self.limit).unwrap_or(usize::MAX);
+        match self.inner.remaining_hint() {
+            Some(hint) => Some(cmp::min(hint, limit)),
+            None if limit == 0 => Some(0),
+            None => None,
+        }
+    }
+}
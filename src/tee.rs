@@ -0,0 +1,101 @@
+use crate::{Result, Write};
+
+/// A writer adapter which writes the same bytes to two writers.
+///
+/// Every byte that a `TeeWriter<A, B>` reports as written has been written to
+/// both `A` and `B`. A [`write`] first writes to `A`, and then writes the
+/// prefix that `A` accepted to `B` in full, so the two writers stay in
+/// lockstep even when `A` performs a short write.
+///
+/// If writing to `B` fails after `A` accepted some bytes, those bytes remain
+/// written to `A` only, and the error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{TeeWriter, Write};
+///
+/// let mut first = [0; 4];
+/// let mut second = [0; 8];
+/// let mut writer = TeeWriter::new(&mut first[..], &mut second[..]);
+///
+/// assert_eq!(writer.write(b"abcdef")?, 4);
+/// assert_eq!(writer.write(b"ef")?, 0);
+///
+/// let (_, rest) = writer.into_inner();
+/// assert_eq!(rest.len(), 4);
+/// assert_eq!(first, *b"abcd");
+/// assert_eq!(second, *b"abcd\0\0\0\0");
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`write`]: Write::write
+#[derive(Debug)]
+pub struct TeeWriter<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> TeeWriter<A, B> {
+    /// Creates a new `TeeWriter<A, B>` which writes to both `a` and `b`.
+    #[must_use]
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        TeeWriter { a, b }
+    }
+
+    /// Gets references to the underlying writers in this `TeeWriter<A, B>`.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.a, &self.b)
+    }
+
+    /// Gets mutable references to the underlying writers in this
+    /// `TeeWriter<A, B>`.
+    ///
+    /// Care should be taken to avoid writing to only one of the underlying
+    /// writers, as they would no longer be in lockstep.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.a, &mut self.b)
+    }
+
+    /// Unwraps the `TeeWriter<A, B>`, returning the underlying writers.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A, B> Write for TeeWriter<A, B>
+where
+    A: Write,
+    B: Write,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let n = self.a.write(buf)?;
+        self.b.write_all(&buf[..n])?;
+        Ok(n)
+    }
+
+    /// Flushes both underlying writers.
+    ///
+    /// `B` is flushed even if flushing `A` fails, in which case the error from
+    /// `A` is returned.
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        let a = self.a.flush();
+        let b = self.b.flush();
+        a.and(b)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.a.write_all(buf)?;
+        self.b.write_all(buf)
+    }
+}
@@ -0,0 +1,384 @@
+use crate::{leb128, ByteOrder, Primitive, Result, Wrapped, Write};
+
+use core::cmp;
+
+macro_rules! write_int_fns {
+    ($($name:ident -> $ty:ident, $to:ident, $endian:literal;)*) => {$(
+        #[doc = concat!("Writes a ", $endian, "-endian [`", stringify!($ty), "`] to this writer.")]
+        ///
+        /// # Errors
+        ///
+        /// This method returns the same errors as [`write_all()`].
+        ///
+        /// [`write_all()`]: Write::write_all
+        #[inline]
+        fn $name(&mut self, value: $ty) -> Result<()> {
+            self.write_all(&value.$to())
+        }
+    )*};
+}
+
+/// The `WriteExt` trait extends every [`Write`] with convenience methods.
+///
+/// [`Write`] only holds the methods that a writer has to provide, and the ones
+/// a writer may want to override for efficiency, such as [`write_all()`] or
+/// [`write_vectored()`]. Everything that is derived from them, like writing
+/// integers, strings, and LEB128 values, lives here instead, so that
+/// implementors of [`Write`] never have to care about it, and adding new
+/// conveniences never affects them.
+///
+/// This trait is implemented for every [`Write`] and cannot be implemented
+/// otherwise. To use its methods, import it alongside [`Write`]:
+///
+/// ```
+/// use zc_io::{Write, WriteExt};
+///
+/// let mut buf = [0; 3];
+/// let mut writer = &mut buf[..];
+/// writer.write_u16_be(0x0102)?;
+/// writer.write_all(b"*")?;
+/// assert_eq!(buf, [0x01, 0x02, 0x2A]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`write_all()`]: Write::write_all
+/// [`write_vectored()`]: Write::write_vectored
+pub trait WriteExt: Write {
+    /// Creates a "by reference" adapter for this instance of `Write`.
+    ///
+    /// The returned adapter also implements `Write` and will simply borrow
+    /// this current writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Write, WriteExt};
+    ///
+    /// fn write_header<W: Write>(mut writer: W) -> zc_io::Result<()> {
+    ///     writer.write_all(b"HDR")
+    /// }
+    ///
+    /// let mut buf = [0; 7];
+    /// let mut writer = &mut buf[..];
+    /// write_header(writer.by_ref())?;
+    /// writer.write_all(b"body")?;
+    /// assert_eq!(buf, *b"HDRbody");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Writes the UTF-8 bytes of `s` to this writer.
+    ///
+    /// No length or terminator is written, so a reader has to know where the
+    /// string ends, for example by writing its length with
+    /// [`write_uleb128()`] first.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{ErrorKind, WriteExt};
+    ///
+    /// let mut buf = [0; 6];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_uleb128(5)?;
+    /// writer.write_str("hello")?;
+    /// assert_eq!(buf, *b"\x05hello");
+    ///
+    /// let mut array = [0; 4];
+    /// let mut slice = &mut array[..];
+    /// let error = slice.write_str("hello").unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::WriteZero);
+    /// assert_eq!(array, *b"hell");
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let mut vec = Vec::new();
+    /// vec.write_uleb128(5)?;
+    /// vec.write_str("hello")?;
+    /// assert_eq!(vec, b"\x05hello");
+    /// # }
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_uleb128()`]: WriteExt::write_uleb128
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<()> {
+        self.write_all(s.as_bytes())
+    }
+
+    /// Writes the UTF-8 bytes of `s` to this writer, followed by a `\n`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::WriteExt;
+    ///
+    /// let mut buf = [0; 13];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_line("first")?;
+    /// writer.write_line("second")?;
+    /// assert_eq!(buf, *b"first\nsecond\n");
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let mut vec = Vec::new();
+    /// vec.write_line("first")?;
+    /// vec.write_line("second")?;
+    /// assert_eq!(vec, b"first\nsecond\n");
+    /// # }
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_line(&mut self, s: &str) -> Result<()> {
+        self.write_str(s)?;
+        self.write_all(b"\n")
+    }
+
+    /// Writes the UTF-8 encoding of `c` to this writer.
+    ///
+    /// The `char` is encoded on the stack, so this never allocates, and its
+    /// one to four bytes are written with a single call to [`write_all()`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::WriteExt;
+    ///
+    /// let mut buf = [0; 1 + 2 + 4];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_char('a')?;
+    /// writer.write_char('é')?;
+    /// writer.write_char('🦀')?;
+    /// assert!(writer.is_empty());
+    /// assert_eq!(buf, "aé🦀".as_bytes());
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_char(&mut self, c: char) -> Result<()> {
+        let mut buf = [0; 4];
+        self.write_all(c.encode_utf8(&mut buf).as_bytes())
+    }
+
+    /// Writes `n` zero bytes to this writer, such as for padding or reserved
+    /// regions.
+    ///
+    /// The zeros are written from a small buffer on the stack, one chunk at a
+    /// time, so nothing is allocated regardless of `n`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`]. If an error
+    /// occurs, an unspecified number of zeros may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Write, WriteExt};
+    ///
+    /// let mut buf = [0xff; 8];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_all(b"abc")?;
+    ///
+    /// let written = 8 - writer.len();
+    /// writer.write_zeros(written.next_multiple_of(8) - written)?;
+    /// assert_eq!(buf, *b"abc\0\0\0\0\0");
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_zeros(&mut self, mut n: usize) -> Result<()> {
+        const ZEROS: [u8; 256] = [0; 256];
+
+        while n > 0 {
+            let len = cmp::min(n, ZEROS.len());
+            self.write_all(&ZEROS[..len])?;
+            n -= len;
+        }
+        Ok(())
+    }
+
+    write_int_fns! {
+        write_u16_le -> u16, to_le_bytes, "little";
+        write_u16_be -> u16, to_be_bytes, "big";
+        write_u32_le -> u32, to_le_bytes, "little";
+        write_u32_be -> u32, to_be_bytes, "big";
+        write_u64_le -> u64, to_le_bytes, "little";
+        write_u64_be -> u64, to_be_bytes, "big";
+        write_u128_le -> u128, to_le_bytes, "little";
+        write_u128_be -> u128, to_be_bytes, "big";
+        write_i16_le -> i16, to_le_bytes, "little";
+        write_i16_be -> i16, to_be_bytes, "big";
+        write_i32_le -> i32, to_le_bytes, "little";
+        write_i32_be -> i32, to_be_bytes, "big";
+        write_i64_le -> i64, to_le_bytes, "little";
+        write_i64_be -> i64, to_be_bytes, "big";
+        write_i128_le -> i128, to_le_bytes, "little";
+        write_i128_be -> i128, to_be_bytes, "big";
+    }
+
+    /// Writes a [`Primitive`] integer `T` in the [`ByteOrder`] `O`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{BigEndian, LittleEndian, ReadExt, WriteExt};
+    ///
+    /// let mut buf = [0; 4];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_int::<u16, BigEndian>(0x1234)?;
+    /// writer.write_int::<i16, LittleEndian>(0x1234)?;
+    /// assert_eq!(buf, [0x12, 0x34, 0x34, 0x12]);
+    ///
+    /// let mut reader = &buf[..];
+    /// assert_eq!(reader.read_u16_be()?, 0x1234);
+    /// assert_eq!(reader.read_i16_le()?, 0x1234);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_int<T, O>(&mut self, value: T) -> Result<()>
+    where
+        T: Primitive,
+        O: ByteOrder,
+    {
+        self.write_all(O::to_bytes(value).as_ref())
+    }
+
+    /// Writes a [`Wrapped`] value, in the byte order determined by its type.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{Be, Le, WriteExt};
+    ///
+    /// let mut buf = [0; 4];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_wrapped(Be(0x0102_u16))?;
+    /// writer.write_wrapped(Le::from(0x0102_u16))?;
+    /// assert_eq!(buf, [0x01, 0x02, 0x02, 0x01]);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_wrapped<T>(&mut self, value: T) -> Result<()>
+    where
+        T: Wrapped,
+    {
+        value.write_to(self)
+    }
+
+    /// Writes an unsigned [LEB128]-encoded [`u64`] to this writer.
+    ///
+    /// The value is encoded with as few bytes as possible, and written with a
+    /// single call to [`write_all()`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::WriteExt;
+    ///
+    /// let mut buf = [0; 3];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_uleb128(624_485)?;
+    /// assert_eq!(buf, [0xe5, 0x8e, 0x26]);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_uleb128(&mut self, value: u64) -> Result<()> {
+        leb128::write_unsigned(self, value.into())
+    }
+
+    /// Writes an unsigned [LEB128]-encoded [`u128`] to this writer.
+    ///
+    /// This is identical to [`write_uleb128()`], except for the type of
+    /// `value`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`write_uleb128()`]: WriteExt::write_uleb128
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_uleb128_u128(&mut self, value: u128) -> Result<()> {
+        leb128::write_unsigned(self, value)
+    }
+
+    /// Writes a signed [LEB128]-encoded [`i64`] to this writer.
+    ///
+    /// The value is encoded with as few bytes as possible, such that
+    /// [`read_sleb128()`] sign-extends it back to `value`, and written
+    /// with a single call to [`write_all()`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns the same errors as [`write_all()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zc_io::{ReadExt, WriteExt};
+    ///
+    /// let mut buf = [0; 12];
+    /// let mut writer = &mut buf[..];
+    /// writer.write_sleb128(-128)?;
+    /// writer.write_sleb128(i64::MAX)?;
+    /// assert!(writer.is_empty());
+    /// assert_eq!(buf[..2], [0x80, 0x7f]);
+    ///
+    /// let mut reader = &buf[..];
+    /// assert_eq!(reader.read_sleb128()?, -128);
+    /// assert_eq!(reader.read_sleb128()?, i64::MAX);
+    /// # Ok::<(), zc_io::Error>(())
+    /// ```
+    ///
+    /// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+    /// [`read_sleb128()`]: crate::ReadExt::read_sleb128
+    /// [`write_all()`]: Write::write_all
+    #[inline]
+    fn write_sleb128(&mut self, value: i64) -> Result<()> {
+        leb128::write_signed(self, value.into())
+    }
+}
+
+impl<W> WriteExt for W where W: ?Sized + Write {}
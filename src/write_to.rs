@@ -0,0 +1,136 @@
+use crate::{Be, Le, Primitive, Result, Write, WriteExt};
+
+/// A type with a fixed binary layout that can be written to any [`Write`].
+///
+/// This is the counterpart of [`ReadFrom`], and is implemented for the same
+/// types: `u8`, `i8`, [`Le<T>`], [`Be<T>`], and arrays of any of them.
+/// Writing a value and reading it back with [`ReadFrom`] yields the same
+/// value.
+///
+/// With the `derive` feature, `#[derive(WriteTo)]` implements this trait for
+/// structs by writing their fields in declaration order.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Be, Result, Write, WriteExt, WriteTo};
+///
+/// struct Version {
+///     major: u8,
+///     minor: u8,
+///     build: Be<u16>,
+/// }
+///
+/// impl WriteTo for Version {
+///     fn write_to<W>(&self, writer: &mut W) -> Result<()>
+///     where
+///         W: ?Sized + Write,
+///     {
+///         self.major.write_to(writer)?;
+///         self.minor.write_to(writer)?;
+///         writer.write_wrapped(self.build)
+///     }
+/// }
+///
+/// let version = Version {
+///     major: 1,
+///     minor: 2,
+///     build: Be(256),
+/// };
+///
+/// let mut buf = [0; 4];
+/// version.write_to(&mut &mut buf[..])?;
+/// assert_eq!(buf, [1, 2, 0x01, 0x00]);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`ReadFrom`]: crate::ReadFrom
+pub trait WriteTo {
+    /// Writes this value to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the errors of the writes it performs.
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write;
+
+    #[doc(hidden)]
+    #[inline]
+    fn write_slice_to<W>(slice: &[Self], writer: &mut W) -> Result<()>
+    where
+        Self: Sized,
+        W: ?Sized + Write,
+    {
+        slice
+            .iter()
+            .try_for_each(|element| element.write_to(writer))
+    }
+}
+
+impl WriteTo for u8 {
+    #[inline]
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_all(&[*self])
+    }
+
+    #[inline]
+    fn write_slice_to<W>(slice: &[Self], writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_all(slice)
+    }
+}
+
+impl WriteTo for i8 {
+    #[inline]
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_all(&self.to_ne_bytes())
+    }
+}
+
+impl<T, const N: usize> WriteTo for [T; N]
+where
+    T: WriteTo,
+{
+    #[inline]
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        T::write_slice_to(self, writer)
+    }
+}
+
+impl<T> WriteTo for Le<T>
+where
+    T: Primitive + Copy,
+{
+    #[inline]
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_wrapped(*self)
+    }
+}
+
+impl<T> WriteTo for Be<T>
+where
+    T: Primitive + Copy,
+{
+    #[inline]
+    fn write_to<W>(&self, writer: &mut W) -> Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        writer.write_wrapped(*self)
+    }
+}
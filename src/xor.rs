@@ -0,0 +1,161 @@
+use crate::{Read, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::slice;
+
+/// A reader adapter which XORs every byte read from an underlying reader with
+/// a repeating key.
+///
+/// The first byte read is masked with the first byte of the key, the second
+/// with the second, and so on, starting over once the key is exhausted. An
+/// empty key leaves every byte unchanged.
+///
+/// Since the bytes are transformed, an `XorReader<'key, R>` never supports
+/// zero-copy operations, even over an in-memory reader, meaning that
+/// [`read_slice`] will always return an [`Owned`] value.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{Read, XorReader};
+///
+/// let mut reader = XorReader::new(&[0x23, 0x20, 0x35, 0x3b][..], b"KEY");
+/// assert_eq!(reader.read_array()?, *b"hel");
+/// assert_eq!(reader.key_position(), 0);
+/// assert_eq!(reader.read_next()?, b'p');
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// [`read_slice`]: Read::read_slice
+/// [`Owned`]: Cow::Owned
+#[derive(Debug)]
+pub struct XorReader<'key, R> {
+    inner: R,
+    key: &'key [u8],
+    pos: usize,
+}
+
+impl<'key, R> XorReader<'key, R> {
+    /// Creates a new `XorReader<'key, R>` which XORs the bytes of `inner` with
+    /// `key`, starting at its first byte.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: R, key: &'key [u8]) -> Self {
+        XorReader { inner, key, pos: 0 }
+    }
+
+    /// Returns the key this reader XORs bytes with.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &'key [u8] {
+        self.key
+    }
+
+    /// Returns the position within the key that the next byte read will be
+    /// masked with.
+    #[must_use]
+    #[inline]
+    pub fn key_position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the position within the key that the next byte read will be
+    /// masked with, such as to resynchronize after reading from the underlying
+    /// reader directly.
+    ///
+    /// The position wraps around the length of the key.
+    #[inline]
+    pub fn set_key_position(&mut self, pos: usize) {
+        self.pos = pos.checked_rem(self.key.len()).unwrap_or(0);
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader don't advance the key
+    /// position.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the `XorReader<'key, R>`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// XORs `buf` with the key, advancing the key position past it.
+    #[inline]
+    fn apply(&mut self, buf: &mut [u8]) {
+        if self.key.is_empty() {
+            return;
+        }
+
+        for byte in buf {
+            *byte ^= self.key[self.pos];
+            self.pos = (self.pos + 1) % self.key.len();
+        }
+    }
+}
+
+impl<'data, R> Read<'data> for XorReader<'_, R>
+where
+    R: Read<'data>,
+{
+    #[inline]
+    fn read_next(&mut self) -> Result<u8> {
+        let mut byte = self.inner.read_next()?;
+        self.apply(slice::from_mut(&mut byte));
+        Ok(byte)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_slice(&mut self, len: usize) -> Result<Cow<'data, [u8]>> {
+        if len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let mut buf = self.inner.read_slice(len)?.into_owned();
+        self.apply(&mut buf);
+        Ok(Cow::Owned(buf))
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = self.inner.read_array()?;
+        self.apply(&mut array);
+        Ok(array)
+    }
+
+    #[inline]
+    fn read_exact_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact_into(buf)?;
+        self.apply(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        self.inner.skip(n)?;
+        if let Some(rem) = n.checked_rem(self.key.len()) {
+            self.set_key_position(self.pos + rem);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn remaining_hint(&self) -> Option<usize> {
+        self.inner.remaining_hint()
+    }
+}
@@ -0,0 +1,123 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Error, Field, Result, Type};
+
+/// The byte order of a field, as given by `#[zc_io(be)]` or `#[zc_io(le)]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    /// Returns the path of the matching `zc_io::ByteOrder` type.
+    pub(crate) fn order(self) -> TokenStream {
+        match self {
+            Endian::Big => quote!(::zc_io::BigEndian),
+            Endian::Little => quote!(::zc_io::LittleEndian),
+        }
+    }
+}
+
+/// The `#[zc_io(...)]` attributes of a struct or one of its fields.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Attrs {
+    pub(crate) endian: Option<Endian>,
+    pub(crate) skip: Option<Span>,
+}
+
+impl Attrs {
+    /// Parses every `#[zc_io(...)]` attribute in `attrs`.
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Attrs::default();
+        for attr in attrs {
+            if !attr.path().is_ident("zc_io") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if parsed.skip.replace(meta.input.span()).is_some() {
+                        return Err(meta.error("duplicate skip"));
+                    }
+                    return Ok(());
+                }
+
+                let endian = if meta.path.is_ident("be") {
+                    Endian::Big
+                } else if meta.path.is_ident("le") {
+                    Endian::Little
+                } else {
+                    return Err(meta.error("unknown zc_io attribute"));
+                };
+
+                if parsed.endian.replace(endian).is_some() {
+                    return Err(meta.error("duplicate byte order"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+
+    /// Parses the attributes of a struct, which may only set a byte order.
+    pub(crate) fn parse_container(attrs: &[Attribute]) -> Result<Self> {
+        let parsed = Attrs::parse(attrs)?;
+        if let Some(span) = parsed.skip {
+            return Err(Error::new(span, "skip is only allowed on fields"));
+        }
+        Ok(parsed)
+    }
+}
+
+/// How a field is read or written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FieldKind {
+    /// The field is not part of the binary layout, and is read as its
+    /// `Default`.
+    Skip,
+    /// The field is a primitive numeric type, or an array of them, in the
+    /// given byte order.
+    Int(Endian),
+    /// The field is read and written with its own implementation.
+    Nested,
+}
+
+/// Determines how `field` of a struct with the `container` attributes is read
+/// or written.
+///
+/// An attribute on the field itself takes precedence. Otherwise, primitive
+/// numeric fields larger than a byte fall back to the byte order of the
+/// `container`, and every other field is left to its own implementation.
+pub(crate) fn field_kind(container: Attrs, field: &Field) -> Result<FieldKind> {
+    let attrs = Attrs::parse(&field.attrs)?;
+    match (attrs.skip, attrs.endian) {
+        (Some(span), Some(_)) => Err(Error::new(span, "skipped fields have no byte order")),
+        (Some(_), None) => Ok(FieldKind::Skip),
+        (None, Some(endian)) => Ok(FieldKind::Int(endian)),
+        (None, None) if !needs_endian(&field.ty) => Ok(FieldKind::Nested),
+        (None, None) => container.endian.map(FieldKind::Int).ok_or_else(|| {
+            Error::new_spanned(
+                &field.ty,
+                "missing byte order, add #[zc_io(be)] or #[zc_io(le)]",
+            )
+        }),
+    }
+}
+
+/// Returns whether `ty` is a primitive numeric type that is larger than a
+/// byte, or an array of them, and thus needs a byte order.
+fn needs_endian(ty: &Type) -> bool {
+    const TYPES: [&str; 10] = [
+        "u16", "u32", "u64", "u128", "i16", "i32", "i64", "i128", "f32", "f64",
+    ];
+
+    match ty {
+        Type::Array(array) => needs_endian(&array.elem),
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| TYPES.iter().any(|name| ident == name)),
+        _ => false,
+    }
+}
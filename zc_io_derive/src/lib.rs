@@ -0,0 +1,131 @@
+//! Derive macros for [`zc_io`].
+//!
+//! This crate is not meant to be used directly. Instead, enable the `derive`
+//! feature of [`zc_io`], which re-exports its macros.
+//!
+//! [`zc_io`]: https://docs.rs/zc_io
+
+// Enable lints:
+#![deny(clippy::pedantic, missing_debug_implementations, missing_docs)]
+
+mod attr;
+mod read_from;
+mod write_to;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `ReadFrom` for a struct, reading its fields in declaration order.
+///
+/// Every field is read with its own `ReadFrom` implementation, which covers
+/// `u8`, `i8`, `Le<T>`, `Be<T>`, other types deriving `ReadFrom`, and arrays of
+/// any of them. Primitive numeric fields larger than a byte, and arrays of
+/// them, need a byte order instead, given by one of these attributes:
+///
+/// * `#[zc_io(be)]` reads the field in big-endian byte order.
+/// * `#[zc_io(le)]` reads the field in little-endian byte order.
+///
+/// On the struct itself, either attribute sets the byte order of every
+/// primitive numeric field that doesn't have its own.
+///
+/// A field marked `#[zc_io(skip)]` isn't part of the binary layout, and is
+/// set to its `Default` value instead of being read.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::ReadFrom;
+///
+/// #[derive(Debug, PartialEq, ReadFrom)]
+/// #[zc_io(be)]
+/// struct Header {
+///     magic: [u8; 4],
+///     version: u16,
+///     #[zc_io(le)]
+///     len: u32,
+/// }
+///
+/// #[derive(Debug, PartialEq, ReadFrom)]
+/// struct Packet {
+///     header: Header,
+///     kind: u8,
+///     #[zc_io(skip)]
+///     checked: bool,
+/// }
+///
+/// let mut reader: &[u8] = b"ZCIO\x00\x02\x10\x00\x00\x00\x07";
+/// let packet = Packet::read_from(&mut reader)?;
+/// assert_eq!(
+///     packet,
+///     Packet {
+///         header: Header {
+///             magic: *b"ZCIO",
+///             version: 2,
+///             len: 16,
+///         },
+///         kind: 7,
+///         checked: false,
+///     },
+/// );
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+///
+/// A primitive numeric field without a byte order is rejected:
+///
+/// ```compile_fail
+/// use zc_io::ReadFrom;
+///
+/// #[derive(ReadFrom)]
+/// struct Header {
+///     len: u32,
+/// }
+/// ```
+#[proc_macro_derive(ReadFrom, attributes(zc_io))]
+pub fn derive_read_from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    read_from::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `WriteTo` for a struct, writing its fields in declaration order.
+///
+/// This is the counterpart of `#[derive(ReadFrom)]`, and accepts the same
+/// attributes, so a struct deriving both writes exactly the bytes it reads.
+/// Every field is written with its own `WriteTo` implementation, unless it has
+/// a byte order, in which case it is written in that byte order. Fields marked
+/// `#[zc_io(skip)]` aren't written at all.
+///
+/// # Examples
+///
+/// ```
+/// use zc_io::{ReadFrom, WriteTo};
+///
+/// #[derive(Debug, PartialEq, ReadFrom, WriteTo)]
+/// #[zc_io(le)]
+/// struct Sample {
+///     id: u16,
+///     values: [i32; 2],
+///     #[zc_io(skip)]
+///     cached: Option<i64>,
+/// }
+///
+/// let sample = Sample {
+///     id: 1,
+///     values: [-1, 2],
+///     cached: None,
+/// };
+///
+/// let mut buf = Vec::new();
+/// sample.write_to(&mut buf)?;
+/// assert_eq!(buf, b"\x01\x00\xff\xff\xff\xff\x02\x00\x00\x00");
+/// assert_eq!(Sample::read_from(&mut buf.as_slice())?, sample);
+/// # Ok::<(), zc_io::Error>(())
+/// ```
+#[proc_macro_derive(WriteTo, attributes(zc_io))]
+pub fn derive_write_to(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    write_to::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,103 @@
+use crate::attr::{self, Attrs, Endian, FieldKind};
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, Data, DeriveInput, Error, Fields, Result, Type};
+
+/// Expands `#[derive(ReadFrom)]` on `input`.
+pub(crate) fn expand(mut input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ReadFrom can only be derived for structs",
+        ));
+    };
+
+    let container = Attrs::parse_container(&input.attrs)?;
+    let mut reads = Vec::new();
+    let mut bounds = Vec::new();
+    for field in &data.fields {
+        let ty = &field.ty;
+        let read = match attr::field_kind(container, field)? {
+            FieldKind::Skip => {
+                bounds.push(quote!(#ty: ::core::default::Default));
+                quote_spanned! {ty.span()=>
+                    <#ty as ::core::default::Default>::default()
+                }
+            }
+            FieldKind::Int(endian) => read_int(ty, endian),
+            FieldKind::Nested => {
+                bounds.push(quote!(#ty: ::zc_io::ReadFrom));
+                quote_spanned! {ty.span()=>
+                    <#ty as ::zc_io::ReadFrom>::read_from(reader)?
+                }
+            }
+        };
+        reads.push(read);
+    }
+
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(Self { #(#names: #reads,)* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#reads,)*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    if input.generics.type_params().next().is_some() {
+        let where_clause = input.generics.make_where_clause();
+        for bound in bounds {
+            where_clause.predicates.push(parse_quote!(#bound));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::zc_io::ReadFrom for #name #ty_generics #where_clause {
+            #[inline]
+            fn read_from<'__data, __R>(reader: &mut __R) -> ::zc_io::Result<Self>
+            where
+                __R: ?::core::marker::Sized + ::zc_io::Read<'__data>,
+            {
+                ::core::result::Result::Ok(#body)
+            }
+        }
+    })
+}
+
+/// Returns an expression reading a primitive numeric type, or an array of
+/// them, in the byte order given by `endian`.
+fn read_int(ty: &Type, endian: Endian) -> TokenStream {
+    if let Type::Array(array) = ty {
+        let element = read_int(&array.elem, endian);
+        let zeroed = zeroed(ty);
+        return quote_spanned! {ty.span()=>
+            {
+                let mut __array: #ty = #zeroed;
+                for __element in &mut __array {
+                    *__element = #element;
+                }
+                __array
+            }
+        };
+    }
+
+    let order = endian.order();
+    quote_spanned! {ty.span()=>
+        ::zc_io::ReadExt::read_int::<#ty, #order>(reader)?
+    }
+}
+
+/// Returns an expression for a zeroed primitive numeric type, or an array of
+/// them, to be overwritten by [`read_int`].
+fn zeroed(ty: &Type) -> TokenStream {
+    if let Type::Array(array) = ty {
+        let element = zeroed(&array.elem);
+        let len = &array.len;
+        quote!([#element; #len])
+    } else {
+        quote!(<#ty as ::core::default::Default>::default())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::attr::{self, Attrs, Endian, FieldKind};
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, Data, DeriveInput, Error, Member, Result, Type};
+
+/// Expands `#[derive(WriteTo)]` on `input`.
+pub(crate) fn expand(mut input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "WriteTo can only be derived for structs",
+        ));
+    };
+
+    let container = Attrs::parse_container(&input.attrs)?;
+    let mut writes = Vec::new();
+    let mut bounds = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let ty = &field.ty;
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::from(index), Member::Named);
+        let write = match attr::field_kind(container, field)? {
+            FieldKind::Skip => continue,
+            FieldKind::Int(endian) => write_int(ty, endian, &quote!(self.#member)),
+            FieldKind::Nested => {
+                bounds.push(quote!(#ty: ::zc_io::WriteTo));
+                quote_spanned! {ty.span()=>
+                    <#ty as ::zc_io::WriteTo>::write_to(&self.#member, writer)?;
+                }
+            }
+        };
+        writes.push(write);
+    }
+
+    if input.generics.type_params().next().is_some() {
+        let where_clause = input.generics.make_where_clause();
+        for bound in bounds {
+            where_clause.predicates.push(parse_quote!(#bound));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::zc_io::WriteTo for #name #ty_generics #where_clause {
+            #[inline]
+            fn write_to<__W>(&self, writer: &mut __W) -> ::zc_io::Result<()>
+            where
+                __W: ?::core::marker::Sized + ::zc_io::Write,
+            {
+                #(#writes)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+/// Returns a statement writing the place `value`, a primitive numeric type or
+/// an array of them, in the byte order given by `endian`.
+fn write_int(ty: &Type, endian: Endian, value: &TokenStream) -> TokenStream {
+    if let Type::Array(array) = ty {
+        let element = write_int(&array.elem, endian, &quote!(*__element));
+        return quote_spanned! {ty.span()=>
+            for __element in &#value {
+                #element
+            }
+        };
+    }
+
+    let order = endian.order();
+    quote_spanned! {ty.span()=>
+        ::zc_io::WriteExt::write_int::<#ty, #order>(writer, #value)?;
+    }
+}
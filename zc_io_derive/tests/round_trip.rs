@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+use zc_io::{Be, Le, ReadFrom, WriteTo};
+
+#[derive(Debug, PartialEq, ReadFrom, WriteTo)]
+#[zc_io(be)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+    #[zc_io(le)]
+    len: u32,
+    flags: i8,
+}
+
+#[derive(Debug, PartialEq, ReadFrom, WriteTo)]
+#[zc_io(le)]
+struct Record {
+    header: Header,
+    samples: [f64; 3],
+    #[zc_io(be)]
+    matrix: [[i16; 2]; 2],
+    wrapped: [Be<u64>; 2],
+    #[zc_io(skip)]
+    cached: Option<u128>,
+    id: i128,
+}
+
+#[derive(Debug, PartialEq, ReadFrom, WriteTo)]
+struct Pair<T>(T, #[zc_io(skip)] Vec<u8>, Le<u32>);
+
+fn header() -> impl Strategy<Value = Header> {
+    (any::<[u8; 4]>(), any::<u16>(), any::<u32>(), any::<i8>()).prop_map(
+        |(magic, version, len, flags)| Header {
+            magic,
+            version,
+            len,
+            flags,
+        },
+    )
+}
+
+fn record() -> impl Strategy<Value = Record> {
+    (
+        header(),
+        any::<[u64; 3]>(),
+        any::<[[i16; 2]; 2]>(),
+        any::<[u64; 2]>(),
+        any::<i128>(),
+    )
+        .prop_map(|(header, samples, matrix, wrapped, id)| Record {
+            header,
+            // Compare the bit patterns of floats, as NaN isn't equal to itself.
+            samples: samples.map(f64::from_bits),
+            matrix,
+            wrapped: wrapped.map(Be),
+            cached: None,
+            id,
+        })
+}
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: ReadFrom + WriteTo,
+{
+    let mut buf = Vec::new();
+    value.write_to(&mut buf).unwrap();
+
+    let mut reader = buf.as_slice();
+    let read = T::read_from(&mut reader).unwrap();
+    assert!(reader.is_empty(), "{} bytes left unread", reader.len());
+    read
+}
+
+proptest! {
+    #[test]
+    fn header_round_trips(header in header()) {
+        prop_assert_eq!(round_trip(&header), header);
+    }
+
+    #[test]
+    fn record_round_trips(record in record()) {
+        let read = round_trip(&record);
+        let bits = |record: &Record| record.samples.map(f64::to_bits);
+        prop_assert_eq!(bits(&read), bits(&record));
+        prop_assert_eq!(read.header, record.header);
+        prop_assert_eq!(read.matrix, record.matrix);
+        prop_assert_eq!(read.wrapped, record.wrapped);
+        prop_assert_eq!(read.id, record.id);
+    }
+
+    #[test]
+    fn skipped_fields_read_as_default(record in record(), cached in any::<u128>()) {
+        let record = Record {
+            cached: Some(cached),
+            ..record
+        };
+        prop_assert_eq!(round_trip(&record).cached, None);
+    }
+
+    #[test]
+    fn generic_round_trips(header in header(), bytes in any::<Vec<u8>>(), len in any::<u32>()) {
+        let pair = Pair(header, bytes, Le(len));
+        let read = round_trip(&pair);
+        prop_assert_eq!(read.0, pair.0);
+        prop_assert!(read.1.is_empty());
+        prop_assert_eq!(read.2, pair.2);
+    }
+}